@@ -1,6 +1,9 @@
+use crossterm::event::KeyEvent;
 use serde::{Deserialize, Serialize};
 use strum::Display;
 
+use crate::{app::Setting, units::Units};
+
 #[derive(Debug, Clone, PartialEq, Eq, Display, Serialize, Deserialize)]
 pub enum Action {
     Tick,
@@ -15,4 +18,113 @@ pub enum Action {
     Up,
     Down,
     Follow,
+    EditLabel,
+    FilterWatched,
+    ToggleAlerts,
+    ToggleMempool,
+    NextMetric,
+    ToggleStats,
+    ToggleWithdrawals,
+    CycleUnits,
+    FocusTxList,
+    FocusAccList,
+    FocusNext,
+    FocusPrevious,
+    Unfocus,
+    Focused(Setting),
+    SelectNext,
+    SelectPrevious,
+    SelectPageDown,
+    SelectPageUp,
+    SelectFirst,
+    SelectLast,
+    OpenDetails,
+    /// Keys typed so far of a sequence which is not yet bound to an action.
+    PendingKeys(Vec<KeyEvent>),
+    OpenCommandLine,
+    /// Shows the result of a command in the command line.
+    Status(String),
+    GotoBlock(u64),
+    /// Selects the block at the given index into the history of blocks,
+    /// newest first.
+    SelectBlock(usize),
+    /// Shows the transaction whose hash starts with the given prefix.
+    ShowTransaction(String),
+    /// Watches the account at the given address or name.
+    Watch(String),
+    /// Stops watching the account at the given address or name, or the
+    /// account selected in the balances list if none is given.
+    Unwatch(Option<String>),
+    SetUnits(Units),
+    SetEndpoint(String),
+    /// Clears the history of blocks, such as after switching endpoint.
+    ClearHistory,
+    /// Exports the transactions of the selected block to a CSV file at the
+    /// given path, or in the data directory if none is given.
+    ExportCsv(Option<String>),
+    OpenSearch,
+    SearchNext,
+    SearchPrevious,
+    ToggleSearchFilter,
+    CycleSort,
+    ReverseSort,
+}
+
+impl Action {
+    /// Describes what the action does, for listing key bindings.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Tick => "Advance the clock",
+            Self::Render => "Redraw the screen",
+            Self::Resize(..) => "Resize the screen",
+            Self::Suspend => "Suspend to the shell",
+            Self::Resume => "Resume from the shell",
+            Self::Quit => "Quit",
+            Self::ClearScreen => "Clear the screen",
+            Self::Error(_) => "Show an error",
+            Self::Help => "Show key bindings",
+            Self::Up => "Select the next newer block",
+            Self::Down => "Select the next older block",
+            Self::Follow => "Follow the head of the chain",
+            Self::EditLabel => "Edit address labels",
+            Self::FilterWatched => "Only show watched transactions",
+            Self::ToggleAlerts => "Show alert history",
+            Self::ToggleMempool => "Show pending transactions",
+            Self::NextMetric => "Chart the next metric",
+            Self::ToggleStats => "Show block statistics",
+            Self::ToggleWithdrawals => "Show validator withdrawals",
+            Self::CycleUnits => "Cycle display units",
+            Self::FocusTxList => "Focus the transactions list",
+            Self::FocusAccList => "Focus the balances list",
+            Self::FocusNext => "Focus the next panel",
+            Self::FocusPrevious => "Focus the previous panel",
+            Self::Unfocus => "Leave the focused panel",
+            Self::Focused(_) => "Focus a panel",
+            Self::SelectNext => "Select the next row",
+            Self::SelectPrevious => "Select the previous row",
+            Self::SelectPageDown => "Select a page down",
+            Self::SelectPageUp => "Select a page up",
+            Self::SelectFirst => "Select the first row",
+            Self::SelectLast => "Select the last row",
+            Self::OpenDetails => "Show the selected row in full",
+            Self::PendingKeys(_) => "Show bindings of a partly typed sequence",
+            Self::OpenCommandLine => "Enter a command",
+            Self::Status(_) => "Show the result of a command",
+            Self::GotoBlock(_) => "Select a block by number",
+            Self::SelectBlock(_) => "Select a block from the history",
+            Self::ShowTransaction(_) => "Show a transaction by hash",
+            Self::Watch(_) => "Watch an account",
+            Self::Unwatch(_) => "Stop watching an account",
+            Self::SetUnits(_) => "Set display units",
+            Self::SetEndpoint(_) => "Switch RPC endpoint",
+            Self::ClearHistory => "Clear the history of blocks",
+            Self::ExportCsv(_) => "Export transactions as CSV",
+            Self::OpenSearch => "Search transactions",
+            Self::SearchNext => "Select the next match",
+            Self::SearchPrevious => "Select the previous match",
+            Self::ToggleSearchFilter => "Only show matching transactions",
+            Self::CycleSort => "Sort transactions by the next column",
+            Self::ReverseSort => "Reverse the order of transactions",
+        }
+    }
 }
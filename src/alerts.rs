@@ -0,0 +1,349 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use alloy::primitives::{
+    utils::{parse_units, UnitsError},
+    U256,
+};
+use chrono::{DateTime, Local};
+use duration_str::deserialize_duration;
+use serde::{de, Deserialize, Deserializer};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+
+use crate::types::{Abridged, Account, Block, Transaction};
+
+pub type AlertSender = UnboundedSender<Vec<Alert>>;
+pub type AlertReceiver = UnboundedReceiver<Vec<Alert>>;
+
+/// Configuration of alerts.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AlertConfig {
+    /// Whether to ring the terminal bell when alerts fire.
+    #[serde(default = "AlertConfig::default_bell")]
+    pub bell: bool,
+    #[serde(default)]
+    pub rules: Vec<AlertRule>,
+}
+
+impl AlertConfig {
+    fn default_bell() -> bool {
+        true
+    }
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            bell: Self::default_bell(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+/// A threshold amount of the native currency of the chain, specified with
+/// units, e.g. `1.5 ether`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Threshold {
+    raw: String,
+    value: U256,
+}
+
+impl Threshold {
+    /// Parses an amount of the form `<number> <units>`. Units default to wei.
+    pub fn parse(raw: &str) -> Result<Self, UnitsError> {
+        let raw = raw.trim();
+        let (number, units) = raw.split_once(' ').unwrap_or((raw, "wei"));
+        let value = parse_units(number, units.trim())?.into();
+        Ok(Self {
+            raw: raw.to_string(),
+            value,
+        })
+    }
+}
+
+impl fmt::Display for Threshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for Threshold {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        Threshold::parse(&raw).map_err(de::Error::custom)
+    }
+}
+
+/// A condition over incoming chain data that raises an alert when met.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertRule {
+    /// The balance of an account drops below an amount.
+    BalanceBelow { address: String, amount: Threshold },
+    /// A transaction transfers more than an amount.
+    ValueAbove(Threshold),
+    /// A transaction is sent from or to an address.
+    Address(String),
+    /// No new block has been seen for a duration.
+    NoBlockFor(#[serde(deserialize_with = "deserialize_duration")] Duration),
+    /// The base fee of a block rises above an amount.
+    BaseFeeAbove(Threshold),
+}
+
+/// A notification raised by an [`AlertRule`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Alert {
+    pub message: String,
+    /// The number of the block which raised the alert, if any.
+    pub block: Option<u64>,
+    pub time: DateTime<Local>,
+}
+
+impl Alert {
+    fn new(message: String, block: Option<u64>) -> Self {
+        Self {
+            message,
+            block,
+            time: Local::now(),
+        }
+    }
+}
+
+/// Evaluates alert rules as chain data arrives.
+///
+/// Rules over continuous values, such as balances and the base fee, only raise
+/// an alert when the condition starts being met rather than on every block.
+#[derive(Debug)]
+pub struct AlertEngine {
+    /// The rules and whether their condition was met at the last evaluation.
+    rules: Vec<(AlertRule, bool)>,
+    last_block_at: Instant,
+}
+
+impl AlertEngine {
+    pub fn new(rules: Vec<AlertRule>) -> Self {
+        Self {
+            rules: rules.into_iter().map(|rule| (rule, false)).collect(),
+            last_block_at: Instant::now(),
+        }
+    }
+
+    /// Evaluates the rules over the data of a new block.
+    pub fn on_block(
+        &mut self,
+        block: &Block,
+        transactions: &[Transaction],
+        accounts: &[Account],
+    ) -> Vec<Alert> {
+        self.last_block_at = Instant::now();
+        let mut alerts = Vec::new();
+        let mut alert = |message: String| alerts.push(Alert::new(message, Some(block.number)));
+        for (rule, active) in self.rules.iter_mut() {
+            match rule {
+                AlertRule::BalanceBelow {
+                    address,
+                    amount: threshold,
+                } => {
+                    let Some(account) = accounts
+                        .iter()
+                        .find(|acc| acc.address.eq_ignore_ascii_case(address))
+                    else {
+                        continue;
+                    };
+                    let below = account.balance.value < threshold.value;
+                    if below && !*active {
+                        alert(format!(
+                            "Balance of {} dropped below {threshold}: {}",
+                            address.abridged(),
+                            account.balance
+                        ));
+                    }
+                    *active = below;
+                }
+                AlertRule::ValueAbove(threshold) => {
+                    for tx in transactions {
+                        if tx.value.value > threshold.value {
+                            alert(format!(
+                                "Transaction {} of {} is above {threshold}",
+                                tx.hash.abridged(),
+                                tx.value
+                            ));
+                        }
+                    }
+                }
+                AlertRule::Address(address) => {
+                    for tx in transactions {
+                        if tx.from.eq_ignore_ascii_case(address)
+                            || tx.to.eq_ignore_ascii_case(address)
+                        {
+                            alert(format!(
+                                "Transaction {} from {} to {}",
+                                tx.hash.abridged(),
+                                tx.from.abridged(),
+                                tx.to.abridged()
+                            ));
+                        }
+                    }
+                }
+                AlertRule::NoBlockFor(_) => *active = false,
+                AlertRule::BaseFeeAbove(threshold) => {
+                    let Some(base_fee) = block.base_fee else {
+                        continue;
+                    };
+                    let above = U256::from(base_fee) > threshold.value;
+                    if above && !*active {
+                        alert(format!(
+                            "Base fee of block {} rose above {threshold}",
+                            block.number
+                        ));
+                    }
+                    *active = above;
+                }
+            }
+        }
+        alerts
+    }
+
+    /// Evaluates the rules which depend on the passing of time rather than on
+    /// new chain data.
+    pub fn on_tick(&mut self) -> Vec<Alert> {
+        let elapsed = self.last_block_at.elapsed();
+        let mut alerts = Vec::new();
+        for (rule, active) in self.rules.iter_mut() {
+            if let AlertRule::NoBlockFor(duration) = rule {
+                if elapsed >= *duration && !*active {
+                    *active = true;
+                    alerts.push(Alert::new(
+                        format!("No new block for {}s", elapsed.as_secs()),
+                        None,
+                    ));
+                }
+            }
+        }
+        alerts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{AlertConfig, AlertEngine, AlertRule, Threshold};
+    use crate::types::{Account, Amount, Block, Transaction};
+
+    const ADDR: &str = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+
+    fn ether(amount: &str) -> Amount {
+        Amount::new(Threshold::parse(amount).unwrap().value, 18, "ETH")
+    }
+
+    fn account(balance: &str) -> Account {
+        Account {
+            address: ADDR.to_string(),
+            balance: ether(balance),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parse_amount() {
+        assert_eq!(
+            Threshold::parse("1.5 ether").unwrap().value,
+            Threshold::parse("1500000000 gwei").unwrap().value
+        );
+        assert_eq!(Threshold::parse("7").unwrap().value.to::<u64>(), 7);
+        assert!(Threshold::parse("1 potato").is_err());
+    }
+
+    #[test]
+    fn parse_config() {
+        let config: AlertConfig = json5::from_str(
+            r#"{
+                rules: [
+                    { balance_below: { address: "0x1", amount: "1 ether" } },
+                    { value_above: "100 ether" },
+                    { address: "0x2" },
+                    { no_block_for: "60s" },
+                    { base_fee_above: "50 gwei" },
+                ],
+            }"#,
+        )
+        .unwrap();
+        assert!(config.bell);
+        assert_eq!(config.rules.len(), 5);
+        assert!(matches!(
+            config.rules[3],
+            AlertRule::NoBlockFor(d) if d == Duration::from_secs(60)
+        ));
+    }
+
+    #[test]
+    fn balance_below() {
+        let rule = AlertRule::BalanceBelow {
+            address: ADDR.to_lowercase(),
+            amount: Threshold::parse("1 ether").unwrap(),
+        };
+        let mut engine = AlertEngine::new(vec![rule]);
+        let block = Block::default();
+        let alerts = |engine: &mut AlertEngine, balance| {
+            engine.on_block(&block, &[], &[account(balance)]).len()
+        };
+        assert_eq!(alerts(&mut engine, "2 ether"), 0);
+        assert_eq!(alerts(&mut engine, "999999999 gwei"), 1);
+        // Does not fire again while still below.
+        assert_eq!(alerts(&mut engine, "5 gwei"), 0);
+        assert_eq!(alerts(&mut engine, "1 ether"), 0);
+        assert_eq!(alerts(&mut engine, "5 gwei"), 1);
+    }
+
+    #[test]
+    fn transactions() {
+        let mut engine = AlertEngine::new(vec![
+            AlertRule::ValueAbove(Threshold::parse("1 ether").unwrap()),
+            AlertRule::Address("0xaaaa".to_string()),
+        ]);
+        let tx = |to: &str, value: &str| Transaction {
+            to: to.to_string(),
+            value: ether(value),
+            ..Default::default()
+        };
+        let txs = [
+            tx("0xAAAA", "1 gwei"),
+            tx("0xbbbb", "2 ether"),
+            tx("0xbbbb", "1 gwei"),
+        ];
+        let alerts = engine.on_block(&Block::default(), &txs, &[]);
+        assert_eq!(alerts.len(), 2);
+    }
+
+    #[test]
+    fn base_fee_above() {
+        let mut engine = AlertEngine::new(vec![AlertRule::BaseFeeAbove(
+            Threshold::parse("1 gwei").unwrap(),
+        )]);
+        let block = |base_fee| Block {
+            base_fee: Some(base_fee),
+            ..Default::default()
+        };
+        assert!(engine.on_block(&block(1), &[], &[]).is_empty());
+        assert_eq!(engine.on_block(&block(2_000_000_000), &[], &[]).len(), 1);
+        assert!(engine.on_block(&block(3_000_000_000), &[], &[]).is_empty());
+    }
+
+    #[test]
+    fn no_block_for() {
+        let mut engine = AlertEngine::new(vec![AlertRule::NoBlockFor(Duration::ZERO)]);
+        assert_eq!(engine.on_tick().len(), 1);
+        // Does not fire again until a new block arrives.
+        assert!(engine.on_tick().is_empty());
+        engine.on_block(&Block::default(), &[], &[]);
+        assert_eq!(engine.on_tick().len(), 1);
+    }
+}
@@ -1,69 +1,215 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use color_eyre::Result;
 use crossterm::event::KeyEvent;
-use ratatui::{
-    layout::{Constraint, Direction, Flex, Layout},
-    prelude::Rect,
-};
+use ratatui::prelude::Rect;
 use serde::{Deserialize, Serialize};
-use tokio::{
-    sync::mpsc,
-    time::{interval, Instant},
-};
-use tracing::{debug, info};
+use tokio::{sync::mpsc, time::interval};
+use tracing::{debug, info, warn};
 
 use crate::{
     action::Action,
-    components::{acclist::AccList, error::Error, head::Head, txlist::TxList, Component},
-    config::Config,
-    monitor::ChainMonitor,
+    components::{
+        acclist::{self, AccList},
+        activity::Activity,
+        alerts::Alerts,
+        charts::Charts,
+        command::CommandLine,
+        details::{DetailSlot, Details},
+        error::Error,
+        gasoracle::GasOracle,
+        head::Head,
+        help::Help,
+        labeleditor::LabelEditor,
+        mempool::Mempool,
+        popup_area,
+        stats::Stats,
+        txlist::{self, TxList},
+        withdrawals::Withdrawals,
+        Component,
+    },
+    config::{get_config_dir, get_data_dir, Config},
+    hooks::HookRunner,
+    keys::KeySequence,
+    labels::AddressBook,
+    layout::Panels,
+    monitor::{ChainMonitor, MonitorCommand, MonitorCommandSender},
+    names::{NameCache, NameService, Names},
     providers::ChainProvider,
+    theme::without_colors,
     tui::{Event, Tui},
+    watchlist::Watchlist,
 };
 
+/// Names of the panels in the layout, in the order of the components.
+const PANELS: [&str; 6] = [
+    "Head",
+    "AccList",
+    "TxList",
+    "Activity",
+    "Charts",
+    "GasOracle",
+];
+
 pub struct App<P> {
     config: Config,
     tick_rate: f64,
     frame_rate: f64,
     components: Vec<Box<dyn Component>>,
+    popups: Vec<Box<dyn Component>>,
+    error: Option<Error>,
     should_quit: bool,
     should_suspend: bool,
     setting: Setting,
-    last_tick_key_events: Vec<KeyEvent>,
+    /// Panels shown, as validated when the app is run.
+    panels: Panels,
+    keys: KeySequence,
     action_tx: mpsc::UnboundedSender<Action>,
     action_rx: mpsc::UnboundedReceiver<Action>,
     monitor: Option<ChainMonitor<P>>,
+    monitor_tx: MonitorCommandSender,
+    name_service: Option<NameService<P>>,
     error_timestamp: Instant,
 }
 
+/// The context in which keys are bound, which is the focused panel if any.
+///
+/// Keys not bound in the context of the focused panel fall back to their
+/// default binding.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Setting {
     #[default]
     Default,
+    TxList,
+    AccList,
+}
+
+impl Setting {
+    /// Every setting, in the order they are focused.
+    pub const ALL: [Self; 3] = [Self::Default, Self::TxList, Self::AccList];
+
+    /// Returns the setting focused after this one when cycling forward.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Default => Self::TxList,
+            Self::TxList => Self::AccList,
+            Self::AccList => Self::Default,
+        }
+    }
+
+    /// Returns the setting focused after this one when cycling backward.
+    pub fn previous(self) -> Self {
+        match self {
+            Self::Default => Self::AccList,
+            Self::TxList => Self::Default,
+            Self::AccList => Self::TxList,
+        }
+    }
+
+    /// Returns the name of the panel focused in this setting, if any.
+    pub fn panel(self) -> Option<&'static str> {
+        match self {
+            Self::Default => None,
+            Self::TxList => Some("TxList"),
+            Self::AccList => Some("AccList"),
+        }
+    }
 }
 
-impl<P: ChainProvider + Send + Sync + 'static> App<P> {
+impl<P: ChainProvider + Clone + Send + Sync + 'static> App<P> {
     pub fn new(tick_rate: f64, frame_rate: f64, provider: P, config: Config) -> Result<Self> {
-        let mut monitor = ChainMonitor::new(provider);
-        let (block_rx, transaction_rx, account_rx) = monitor.receivers();
+        let names = Names::default();
+        let labels = AddressBook::load(&get_config_dir(), &config.app.labels)?;
+        let watchlist = Watchlist::default();
+        let mut monitor = ChainMonitor::new(provider.clone(), watchlist.clone())
+            .watchlist_refresh_rate(config.app.ens.refresh_rate)
+            .alert_rules(config.app.alerts.rules.clone())
+            .mempool(config.app.mempool)
+            .stuck_after(config.app.stuck_after);
+        if !config.app.hooks.hooks.is_empty() {
+            monitor = monitor.hooks(HookRunner::new(config.app.hooks.clone()));
+        }
+
+        // Look up names of addresses in the background if enabled.
+        let name_service = if config.app.ens.enabled {
+            let (name_tx, name_rx) = mpsc::unbounded_channel();
+            monitor = monitor.name_requests(name_tx);
+            let cache = NameCache::load(&get_data_dir(), config.app.ens.cache_ttl);
+            Some(NameService::new(provider, cache, names.clone(), name_rx))
+        } else {
+            None
+        };
+
         let (action_tx, action_rx) = mpsc::unbounded_channel();
+        let (monitor_tx, monitor_rx) = mpsc::unbounded_channel();
+        monitor = monitor.commands(monitor_rx, action_tx.clone());
+        let receivers = monitor.receivers();
+        let details = DetailSlot::default();
         Ok(Self {
             tick_rate,
             frame_rate,
             config,
             components: vec![
-                Box::new(Head::new(block_rx)),
-                Box::new(AccList::new(account_rx)),
-                Box::new(TxList::new(transaction_rx)),
+                Box::new(Head::new(receivers.blocks, labels.clone(), names.clone())),
+                Box::new(AccList::new(
+                    receivers.accounts,
+                    labels.clone(),
+                    names.clone(),
+                    details.clone(),
+                )),
+                Box::new(TxList::new(
+                    receivers.transactions,
+                    labels.clone(),
+                    names.clone(),
+                    watchlist.clone(),
+                    details.clone(),
+                )),
+                Box::new(Activity::new(
+                    receivers.activity,
+                    labels.clone(),
+                    names.clone(),
+                    watchlist.clone(),
+                )),
+                Box::new(Charts::new(
+                    receivers.chart_blocks,
+                    receivers.chart_accounts,
+                    labels.clone(),
+                    names.clone(),
+                )),
+                Box::new(GasOracle::new(receivers.fees)),
             ],
+            popups: vec![
+                Box::new(Stats::new(receivers.stats, labels.clone(), names.clone())),
+                Box::new(Withdrawals::new(
+                    receivers.withdrawals,
+                    labels.clone(),
+                    names.clone(),
+                    watchlist.clone(),
+                )),
+                Box::new(Mempool::new(
+                    receivers.pending,
+                    receivers.included,
+                    labels.clone(),
+                    names.clone(),
+                    watchlist.clone(),
+                )),
+                Box::new(Details::new(details, labels.clone(), names)),
+                Box::new(CommandLine::new(labels.clone(), watchlist)),
+                Box::new(LabelEditor::new(labels)),
+                Box::new(Alerts::new(receivers.alerts)),
+                Box::new(Help::default()),
+            ],
+            error: None,
             should_quit: false,
             should_suspend: false,
             setting: Setting::Default,
-            last_tick_key_events: Vec::new(),
+            panels: Panels::default(),
+            keys: KeySequence::default(),
             action_tx,
             action_rx,
             monitor: monitor.into(),
+            monitor_tx,
+            name_service,
             error_timestamp: Instant::now(),
         })
     }
@@ -74,15 +220,34 @@ impl<P: ChainProvider + Send + Sync + 'static> App<P> {
             .frame_rate(self.frame_rate);
         tui.enter()?;
 
-        for component in self.components.iter_mut() {
+        for component in self.components.iter_mut().chain(self.popups.iter_mut()) {
             component.register_action_handler(self.action_tx.clone())?;
         }
-        for component in self.components.iter_mut() {
+        for component in self.components.iter_mut().chain(self.popups.iter_mut()) {
             component.register_config_handler(self.config.clone())?;
         }
-        for component in self.components.iter_mut() {
+        for component in self.components.iter_mut().chain(self.popups.iter_mut()) {
             component.init(tui.size()?)?;
         }
+        // Warn once about invalid entries in the layout, which fall back to
+        // the defaults.
+        let layout = &self.config.app.layout;
+        let (panels, mut warnings) = layout.panels(&PANELS);
+        self.panels = panels;
+        warnings.extend(layout.columns("TxList", &txlist::COLUMNS).1);
+        warnings.extend(layout.columns("AccList", &acclist::COLUMNS).1);
+        warnings.extend(layout.unsupported_tables(&["TxList", "AccList"]));
+        for warning in &warnings {
+            warn!("{warning}");
+        }
+        if !warnings.is_empty() {
+            self.action_tx.send(Action::Error(warnings.join("\n")))?;
+        }
+
+        // Run name service loop.
+        if let Some(name_service) = self.name_service.take() {
+            tokio::task::spawn(name_service.run());
+        }
 
         // Run chain monitor loop.
         let mut monitor = self.monitor.take().unwrap();
@@ -129,10 +294,14 @@ impl<P: ChainProvider + Send + Sync + 'static> App<P> {
             Event::Tick => action_tx.send(Action::Tick)?,
             Event::Render => action_tx.send(Action::Render)?,
             Event::Resize(x, y) => action_tx.send(Action::Resize(x, y))?,
-            Event::Key(key) => self.handle_key_event(key)?,
+            Event::Key(key) if !self.is_capturing_keys() => self.handle_key_event(key)?,
             _ => {}
         }
-        for component in self.components.iter_mut() {
+        // Popups capturing keys take them from the panels underneath.
+        let skip_components = matches!(event, Event::Key(_))
+            && self.popups.iter().any(|popup| popup.is_capturing_keys());
+        let components = self.components.iter_mut().filter(|_| !skip_components);
+        for component in components.chain(self.popups.iter_mut()) {
             if let Some(action) = component.handle_events(Some(event.clone()))? {
                 action_tx.send(action)?;
             }
@@ -141,27 +310,33 @@ impl<P: ChainProvider + Send + Sync + 'static> App<P> {
     }
 
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<()> {
-        let action_tx = self.action_tx.clone();
-        let Some(keymap) = self.config.keybindings.get(&self.setting) else {
+        let pending = !self.keys.keys().is_empty();
+        let actions = self
+            .keys
+            .push(key, &self.config.keybindings, self.setting, Instant::now());
+        for action in actions {
+            info!("Got action: {action:?}");
+            self.action_tx.send(action)?;
+        }
+        // Hint at how a partly typed sequence can be completed.
+        if pending || !self.keys.keys().is_empty() {
+            self.action_tx
+                .send(Action::PendingKeys(self.keys.keys().to_vec()))?;
+        }
+        Ok(())
+    }
+
+    /// Ends a key sequence which has waited too long for its next key,
+    /// performing the action bound to the keys typed so far if any.
+    fn expire_keys(&mut self, now: Instant) -> Result<()> {
+        if !self.keys.is_expired(self.config.app.key_timeout, now) {
             return Ok(());
-        };
-        match keymap.get(&vec![key]) {
-            Some(action) => {
-                info!("Got action: {action:?}");
-                action_tx.send(action.clone())?;
-            }
-            _ => {
-                // If the key was not handled as a single key action,
-                // then consider it for multi-key combinations.
-                self.last_tick_key_events.push(key);
-
-                // Check for multi-key combinations
-                if let Some(action) = keymap.get(&self.last_tick_key_events) {
-                    info!("Got action: {action:?}");
-                    action_tx.send(action.clone())?;
-                }
-            }
         }
+        if let Some(action) = self.keys.take() {
+            info!("Got action: {action:?}");
+            self.action_tx.send(action)?;
+        }
+        self.action_tx.send(Action::PendingKeys(Vec::new()))?;
         Ok(())
     }
 
@@ -172,12 +347,12 @@ impl<P: ChainProvider + Send + Sync + 'static> App<P> {
             }
             match action {
                 Action::Tick => {
-                    self.last_tick_key_events.drain(..);
+                    self.expire_keys(Instant::now())?;
                     if self.is_error()
                         && Instant::now().duration_since(self.error_timestamp)
                             > Duration::from_secs(4)
                     {
-                        self.components.pop();
+                        self.error = None;
                     }
                 }
                 Action::Quit => self.should_quit = true,
@@ -186,17 +361,34 @@ impl<P: ChainProvider + Send + Sync + 'static> App<P> {
                 Action::ClearScreen => tui.terminal.clear()?,
                 Action::Resize(w, h) => self.handle_resize(tui, w, h)?,
                 Action::Render => self.render(tui)?,
+                Action::FocusNext => self.focus(self.cycle_focus(Setting::next))?,
+                Action::FocusPrevious => self.focus(self.cycle_focus(Setting::previous))?,
+                Action::FocusTxList => self.focus(Setting::TxList)?,
+                Action::FocusAccList => self.focus(Setting::AccList)?,
+                Action::Unfocus => self.focus(Setting::Default)?,
+                // The monitor reports the outcome once it applies the command.
+                Action::Watch(ref account) => {
+                    self.monitor_tx
+                        .send(MonitorCommand::Watch(account.clone()))?;
+                }
+                Action::Unwatch(Some(ref account)) => {
+                    self.monitor_tx
+                        .send(MonitorCommand::Unwatch(account.clone()))?;
+                }
+                Action::SetEndpoint(ref url) => {
+                    self.monitor_tx
+                        .send(MonitorCommand::Endpoint(url.clone()))?;
+                }
                 Action::Error(ref err) => {
                     self.error_timestamp = Instant::now();
-                    if self.is_error() {
-                        self.components[3] = Box::new(Error::new(err.to_string()));
-                    } else {
-                        self.components.push(Box::new(Error::new(err.to_string())));
-                    }
+                    self.error = Some(Error::new(
+                        err.to_string(),
+                        self.config.theme.get("error.border"),
+                    ));
                 }
                 _ => {}
             }
-            for component in self.components.iter_mut() {
+            for component in self.components.iter_mut().chain(self.popups.iter_mut()) {
                 if let Some(action) = component.update(action.clone())? {
                     self.action_tx.send(action)?
                 };
@@ -205,6 +397,36 @@ impl<P: ChainProvider + Send + Sync + 'static> App<P> {
         Ok(())
     }
 
+    /// Returns the setting focused after the current one when cycling in the
+    /// given direction, skipping panels left out of the layout.
+    fn cycle_focus(&self, step: fn(Setting) -> Setting) -> Setting {
+        let mut setting = step(self.setting);
+        while !self.is_shown(setting) {
+            setting = step(setting);
+        }
+        setting
+    }
+
+    /// Returns true if the panel of the setting is shown, as is the setting
+    /// without one.
+    fn is_shown(&self, setting: Setting) -> bool {
+        setting
+            .panel()
+            .is_none_or(|panel| self.panels.contains(panel))
+    }
+
+    /// Scopes key bindings to the panel of the setting and lets components
+    /// know which panel is focused. Panels left out of the layout cannot be
+    /// focused.
+    fn focus(&mut self, setting: Setting) -> Result<()> {
+        if !self.is_shown(setting) {
+            return Ok(());
+        }
+        self.setting = setting;
+        self.action_tx.send(Action::Focused(setting))?;
+        Ok(())
+    }
+
     fn handle_resize(&mut self, tui: &mut Tui, w: u16, h: u16) -> Result<()> {
         tui.resize(Rect::new(0, 0, w, h))?;
         self.render(tui)?;
@@ -213,42 +435,170 @@ impl<P: ChainProvider + Send + Sync + 'static> App<P> {
 
     fn render(&mut self, tui: &mut Tui) -> Result<()> {
         tui.draw(|frame| {
-            let outer_layout = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints(vec![Constraint::Percentage(20), Constraint::Percentage(80)])
-                .split(frame.area());
-
-            let inner_layout = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints(vec![Constraint::Percentage(60), Constraint::Percentage(40)])
-                .split(outer_layout[0]);
-
-            let mut areas = vec![inner_layout[0], inner_layout[1], outer_layout[1]];
-            if self.is_error() {
-                areas.push(Self::popup_area(frame.area(), 50, 30));
-            }
-            for (i, component) in self.components.iter_mut().enumerate() {
-                if let Err(err) = component.draw(frame, areas[i]) {
+            let panels = self.panels.areas(frame.area());
+            let mut areas = PANELS
+                .iter()
+                .map(|name| {
+                    panels
+                        .iter()
+                        .find(|(panel, _)| panel == name)
+                        .map(|(_, area)| *area)
+                })
+                .collect::<Vec<_>>();
+            areas.extend(self.popups.iter().map(|_| Some(frame.area())));
+            let popups = self.popups.iter_mut();
+            for (component, area) in self.components.iter_mut().chain(popups).zip(areas) {
+                // Panels left out of the layout are not drawn.
+                let Some(area) = area else {
+                    continue;
+                };
+                if let Err(err) = component.draw(frame, area) {
                     let _ = self
                         .action_tx
                         .send(Action::Error(format!("Failed to draw: {:?}", err)));
                 }
             }
+            if let Some(error) = self.error.as_mut() {
+                let _ = error.draw(frame, popup_area(frame.area(), 50, 30));
+            }
+            // Leave out colors, including those of labels, if asked to.
+            if !self.config.theme.colors {
+                for cell in frame.buffer_mut().content.iter_mut() {
+                    cell.set_style(without_colors(cell.style()));
+                }
+            }
         })?;
         Ok(())
     }
 
     /// Returns true if the app is in an error state.
     fn is_error(&self) -> bool {
-        self.components.len() == 4
+        self.error.is_some()
+    }
+
+    /// Returns true if any component is consuming key events itself.
+    fn is_capturing_keys(&self) -> bool {
+        self.components
+            .iter()
+            .chain(self.popups.iter())
+            .any(|component| component.is_capturing_keys())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use pretty_assertions::assert_eq;
+
+    use super::{App, Setting, PANELS};
+    use crate::{
+        action::Action,
+        config::{parse_key_sequence, Config},
+        layout::LayoutConfig,
+        providers::eth::EthProvider,
+    };
+
+    /// An app with the given sequences bound in addition to the default ones.
+    fn app(bindings: &[(&str, Action)]) -> App<EthProvider> {
+        let mut config = Config::new().unwrap();
+        let keymap = config.keybindings.entry(Setting::Default).or_default();
+        for (keys, action) in bindings {
+            keymap.insert(parse_key_sequence(keys).unwrap(), action.clone());
+        }
+        let provider = EthProvider::new("http://localhost:8545".parse().unwrap(), &[]).unwrap();
+        App::new(4.0, 60.0, provider, config).unwrap()
+    }
+
+    /// Types the keys of a sequence, returning the actions sent.
+    fn type_keys(app: &mut App<EthProvider>, keys: &str) -> Vec<Action> {
+        for key in parse_key_sequence(keys).unwrap() {
+            app.handle_key_event(key).unwrap();
+        }
+        sent(app)
+    }
+
+    /// Returns the actions sent, leaving out hints of pending keys.
+    fn sent(app: &mut App<EthProvider>) -> Vec<Action> {
+        let mut actions = Vec::new();
+        while let Ok(action) = app.action_rx.try_recv() {
+            if !matches!(action, Action::PendingKeys(_)) {
+                actions.push(action);
+            }
+        }
+        actions
+    }
+
+    #[test]
+    fn key_sequences() {
+        let mut app = app(&[("<x><y>", Action::ToggleStats)]);
+        assert_eq!(type_keys(&mut app, "<q>"), vec![Action::Quit]);
+
+        // Sequences do not depend on ticks arriving between their keys.
+        assert_eq!(type_keys(&mut app, "<x>"), vec![]);
+        app.expire_keys(Instant::now()).unwrap();
+        assert_eq!(type_keys(&mut app, "<y>"), vec![Action::ToggleStats]);
+
+        // A key which cannot extend a sequence abandons it and is typed afresh.
+        assert_eq!(type_keys(&mut app, "<x><q>"), vec![Action::Quit]);
+        assert_eq!(type_keys(&mut app, "<x><x><y>"), vec![Action::ToggleStats]);
+        assert!(app.keys.keys().is_empty());
+    }
+
+    #[test]
+    fn ambiguous_key_sequences() {
+        let mut app = app(&[("<g>", Action::Follow), ("<g><g>", Action::ToggleStats)]);
+        assert_eq!(type_keys(&mut app, "<g><g>"), vec![Action::ToggleStats]);
+
+        // The shorter sequence waits for the timeout.
+        assert_eq!(type_keys(&mut app, "<g>"), vec![]);
+        app.expire_keys(Instant::now()).unwrap();
+        assert_eq!(sent(&mut app), vec![]);
+        app.expire_keys(Instant::now() + Duration::from_secs(2))
+            .unwrap();
+        assert_eq!(sent(&mut app), vec![Action::Follow]);
+
+        // Or is performed when the next key cannot extend it.
+        assert_eq!(
+            type_keys(&mut app, "<g><q>"),
+            vec![Action::Follow, Action::Quit]
+        );
+        assert_eq!(type_keys(&mut app, "<g><g><g>"), vec![Action::ToggleStats]);
+        app.expire_keys(Instant::now() + Duration::from_secs(2))
+            .unwrap();
+        assert_eq!(sent(&mut app), vec![Action::Follow]);
+    }
+
+    #[test]
+    fn key_sequences_in_focused_panel() {
+        let mut app = app(&[("<g><g>", Action::ToggleStats)]);
+        // Sequences bound in the panel and by default both extend `g`.
+        app.setting = Setting::TxList;
+        app.config
+            .keybindings
+            .get_mut(&Setting::TxList)
+            .unwrap()
+            .insert(parse_key_sequence("<g><x>").unwrap(), Action::SelectLast);
+        assert_eq!(type_keys(&mut app, "<g><x>"), vec![Action::SelectLast]);
+        assert_eq!(type_keys(&mut app, "<g><g>"), vec![Action::ToggleStats]);
+        assert_eq!(type_keys(&mut app, "<j>"), vec![Action::SelectNext]);
     }
 
-    /// Returns a centered rect using up certain percentage of the available rect `r`.
-    fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
-        let vertical = Layout::vertical([Constraint::Percentage(percent_y)]).flex(Flex::Center);
-        let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)]).flex(Flex::Center);
-        let [area] = vertical.areas(area);
-        let [area] = horizontal.areas(area);
-        area
+    #[test]
+    fn focus_skips_hidden_panels() {
+        let mut app = app(&[]);
+        let layout: LayoutConfig = json5::from_str(
+            r#"{ panels: { direction: "vertical", children: [{ panel: "AccList" }] } }"#,
+        )
+        .unwrap();
+        (app.panels, _) = layout.panels(&PANELS);
+        app.focus(app.cycle_focus(Setting::next)).unwrap();
+        assert_eq!(app.setting, Setting::AccList);
+        app.focus(app.cycle_focus(Setting::next)).unwrap();
+        assert_eq!(app.setting, Setting::Default);
+        app.focus(app.cycle_focus(Setting::previous)).unwrap();
+        assert_eq!(app.setting, Setting::AccList);
+        app.focus(Setting::TxList).unwrap();
+        assert_eq!(app.setting, Setting::AccList);
     }
 }
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use clap::Parser;
 use url::Url;
 
@@ -14,6 +16,10 @@ pub struct Cli {
     #[arg(short, long, value_name = "FLOAT", default_value_t = 60.0)]
     pub frame_rate: f64,
 
+    /// Import address labels from a CSV file exported from a block explorer
+    #[arg(long, value_name = "FILE")]
+    pub import_labels: Option<PathBuf>,
+
     /// ...
     pub rpc_url: Url,
 }
@@ -1,18 +1,39 @@
+use std::cmp::Ordering;
+
 use color_eyre::Result;
 use crossterm::event::{KeyEvent, MouseEvent};
 use ratatui::{
-    layout::{Rect, Size},
+    layout::{Constraint, Flex, Layout, Rect, Size},
+    style::Style,
+    text::Span,
+    widgets::Cell,
     Frame,
 };
 use tokio::sync::mpsc::UnboundedSender;
 
-use crate::{action::Action, config::Config, tui::Event};
+use crate::{
+    action::Action, config::Config, labels::AddressBook, names::Names, theme::Theme, tui::Event,
+    types::Amount, units::Units,
+};
+use alloy::primitives::{utils::format_units, U256};
 
 pub mod acclist;
+pub mod activity;
+pub mod alerts;
+pub mod charts;
+pub mod command;
+pub mod details;
 pub mod error;
+pub mod gasoracle;
 pub mod head;
+pub mod help;
 pub mod interactive;
+pub mod labeleditor;
+pub mod mempool;
+pub mod selection;
+pub mod stats;
 pub mod txlist;
+pub mod withdrawals;
 
 /// `Component` is a trait that represents a visual and interactive element of the user interface.
 ///
@@ -101,6 +122,16 @@ pub trait Component {
         let _ = mouse; // to appease clippy
         Ok(None)
     }
+    /// Whether the component is consuming key events itself, e.g. for text input.
+    ///
+    /// Key bindings are not applied while any component is capturing keys.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - True if key bindings should be suspended.
+    fn is_capturing_keys(&self) -> bool {
+        false
+    }
     /// Update the state of the component based on a received action. (REQUIRED)
     ///
     /// # Arguments
@@ -126,3 +157,110 @@ pub trait Component {
     /// * `Result<()>` - An Ok result or an error.
     fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()>;
 }
+
+/// Returns a centered rect using up certain percentage of the available rect `r`.
+pub fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
+    let vertical = Layout::vertical([Constraint::Percentage(percent_y)]).flex(Flex::Center);
+    let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)]).flex(Flex::Center);
+    let [area] = vertical.areas(area);
+    let [area] = horizontal.areas(area);
+    area
+}
+
+/// Returns a span displaying the label of an address if it has one, its name if
+/// it has one, or the abridged address otherwise.
+pub fn address_span(address: &str, labels: &AddressBook, names: &Names) -> Span<'static> {
+    match labels.get(address) {
+        Some(label) => Span::styled(label.name.clone(), label.style()),
+        None => Span::styled(names.display(address), Style::default()),
+    }
+}
+
+/// Returns a cell displaying an address as per [`address_span`].
+pub fn address_cell(address: &str, labels: &AddressBook, names: &Names) -> Cell<'static> {
+    Cell::from(address_span(address, labels, names))
+}
+
+/// Formats a fee, or a price per unit of gas, in the smallest unit of the
+/// native currency as gwei, or as wei if it is too small to show that way.
+pub fn fee(amount: impl Into<u128>) -> String {
+    let amount: u128 = amount.into();
+    let units = if amount < 1_000_000 {
+        Units::Wei
+    } else {
+        Units::Gwei
+    };
+    units.format_with_symbol(&Amount::new(U256::from(amount), 18, "ETH"), 3)
+}
+
+/// Converts an amount to a float for plotting.
+pub fn amount_f64(amount: &Amount) -> f64 {
+    format_units(amount.value, amount.decimals)
+        .ok()
+        .and_then(|formatted| formatted.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Returns a span of the change between two amounts in the given units,
+/// styled for gain or loss.
+pub fn delta_span(
+    from: &Amount,
+    to: &Amount,
+    units: Units,
+    decimals: usize,
+    theme: &Theme,
+) -> Span<'static> {
+    let difference = |value| {
+        let amount = Amount {
+            value,
+            ..to.clone()
+        };
+        units.format(&amount, decimals)
+    };
+    match to.value.cmp(&from.value) {
+        Ordering::Greater => Span::styled(
+            format!("+{}", difference(to.value - from.value)),
+            theme.get("acclist.delta_up"),
+        ),
+        Ordering::Less => Span::styled(
+            format!("-{}", difference(from.value - to.value)),
+            theme.get("acclist.delta_down"),
+        ),
+        Ordering::Equal => Span::from("0"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::U256;
+    use pretty_assertions::assert_eq;
+
+    use super::{delta_span, fee};
+    use crate::{theme::Theme, types::Amount, units::Units};
+
+    #[test]
+    fn deltas() {
+        let ether = |wei: u128| Amount::new(U256::from(wei), 18, "ETH");
+        let (zero, one) = (ether(0), ether(1_500_000_000_000_000_000));
+        let theme = Theme::default();
+        assert_eq!(
+            delta_span(&one, &zero, Units::Native, 1, &theme).content,
+            "-1.5"
+        );
+        assert_eq!(
+            delta_span(&zero, &one, Units::Gwei, 0, &theme).content,
+            "+1,500,000,000"
+        );
+        assert_eq!(
+            delta_span(&one, &one, Units::Native, 1, &theme).content,
+            "0"
+        );
+    }
+
+    #[test]
+    fn fees() {
+        assert_eq!(fee(21_000u64), "21,000 wei");
+        assert_eq!(fee(1_500_000_000u64), "1.500 gwei");
+        assert_eq!(fee(1_234_567_891_000u128), "1,234.567 gwei");
+    }
+}
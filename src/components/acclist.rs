@@ -1,24 +1,73 @@
-use super::Component;
+use super::{
+    address_cell, delta_span,
+    details::{Detail, DetailSlot},
+    fee,
+    selection::Selection,
+    Component,
+};
 use crate::components::interactive::Interactive;
 use crate::{
     action::Action,
+    app::Setting,
     config::Config,
-    types::{Abridged, Account, AccountReceiver},
+    labels::AddressBook,
+    layout::{pick, Column},
+    names::Names,
+    theme::Theme,
+    types::{Abridged, Account, AccountReceiver, Amount, Stuck},
+    units::Units,
 };
+use alloy::primitives::U256;
 use color_eyre::Result;
 use ratatui::{prelude::*, widgets::*};
+use std::collections::HashMap;
 use tokio::sync::mpsc::UnboundedSender;
 
+/// Number of blocks of balance history shown per account.
+const HISTORY: usize = 20;
+
+/// Bars of increasing height used to draw the balance history.
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Columns of the accounts table, and their default widths.
+pub const COLUMNS: [Column; 6] = [
+    Column::new("address", Constraint::Min(11)),
+    // Nonce, and pending nonce if ahead.
+    Column::new("nonce", Constraint::Min(5)),
+    Column::new("balance", Constraint::Percentage(100)),
+    // Change since the previous block.
+    Column::new("block_delta", Constraint::Min(10)),
+    // Change since the session started.
+    Column::new("session_delta", Constraint::Min(10)),
+    Column::new("history", Constraint::Length(HISTORY as u16)),
+];
+
 #[derive(Default)]
 pub struct AccList {
     command_tx: Option<UnboundedSender<Action>>,
     config: Config,
+    labels: AddressBook,
+    names: Names,
     interact: Interactive<Account>,
+    /// Balance of each account when first seen in the session.
+    initial: HashMap<String, Amount>,
+    /// Units in which amounts are displayed.
+    units: Units,
+    selection: Selection,
+    details: DetailSlot,
 }
 
 impl AccList {
-    pub fn new(account_rx: AccountReceiver) -> Self {
+    pub fn new(
+        account_rx: AccountReceiver,
+        labels: AddressBook,
+        names: Names,
+        details: DetailSlot,
+    ) -> Self {
         Self {
+            labels,
+            names,
+            details,
             interact: Interactive {
                 elems_rx: account_rx.into(),
                 ..Default::default()
@@ -26,6 +75,90 @@ impl AccList {
             ..Default::default()
         }
     }
+
+    /// Balances of an account over the blocks up to the selected one, oldest
+    /// first.
+    fn history(&self, address: &str) -> Vec<U256> {
+        let mut balances = self
+            .interact
+            .elems
+            .iter()
+            .skip(self.interact.index)
+            .take(HISTORY)
+            .filter_map(|accs| accs.iter().find(|acc| acc.address == address))
+            .map(|acc| acc.balance.value)
+            .collect::<Vec<_>>();
+        balances.reverse();
+        balances
+    }
+
+    /// Balance of an account in the block before the selected one.
+    fn previous(&self, address: &str) -> Option<Amount> {
+        self.interact
+            .elems
+            .get(self.interact.index + 1)?
+            .iter()
+            .find(|acc| acc.address == address)
+            .map(|acc| acc.balance.clone())
+    }
+}
+
+/// Draws balances as bars scaled between their minimum and maximum.
+fn sparkline(balances: &[U256]) -> String {
+    let (Some(min), Some(max)) = (balances.iter().min(), balances.iter().max()) else {
+        return String::new();
+    };
+    let range = max - min;
+    balances
+        .iter()
+        .map(|balance| {
+            if range.is_zero() {
+                return BARS[0];
+            }
+            let level = (balance - min) * U256::from(BARS.len() - 1) / range;
+            BARS[level.to::<usize>()]
+        })
+        .collect()
+}
+
+/// Spans of the balance of an account, explaining any part of it which was
+/// withdrawn from validators in the block.
+fn balance_spans(
+    acc: &Account,
+    units: Units,
+    decimals: usize,
+    theme: &Theme,
+) -> Vec<Span<'static>> {
+    let mut spans = vec![Span::from(units.format(&acc.balance, decimals))];
+    if let Some(withdrawn) = &acc.withdrawn {
+        let withdrawn = units.format(withdrawn, decimals);
+        spans.push(Span::styled(
+            format!(" (+{withdrawn} withdrawn)"),
+            theme.get("acclist.withdrawn"),
+        ));
+    }
+    spans
+}
+
+/// Describes the pending transaction holding up a stuck account and how its
+/// gas price compares with the base fee.
+fn stuck_line(stuck: &Stuck) -> Line<'static> {
+    let mut spans = vec![Span::from(format!("STUCK {} blocks", stuck.blocks)).bold()];
+    if let Some(tx) = &stuck.transaction {
+        spans.push(Span::from(format!(
+            " {} at {}",
+            tx.hash.abridged(),
+            fee(tx.gas_price)
+        )));
+        if let Some(base_fee) = stuck.base_fee {
+            spans.push(Span::from(format!(", base fee {}", fee(base_fee))));
+            // A gas price below the base fee cannot be included until the base fee drops.
+            if tx.gas_price < u128::from(base_fee) {
+                spans.push(Span::from(" (underpriced)").bold());
+            }
+        }
+    }
+    Line::from(spans)
 }
 
 impl Component for AccList {
@@ -35,28 +168,116 @@ impl Component for AccList {
     }
 
     fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.units = config.app.units("AccList");
         self.config = config;
         Ok(())
     }
 
     fn update(&mut self, action: Action) -> Result<Option<Action>> {
-        self.interact.update(action)
+        let accounts = self.interact.get().map(Vec::as_slice).unwrap_or_default();
+        if self.selection.update(&action, accounts.len()) {
+            if let Some(acc) = self
+                .selection
+                .selected(accounts.len())
+                .map(|i| &accounts[i])
+            {
+                self.details.open(Detail::Account(Box::new(acc.clone())));
+            }
+        }
+        match action {
+            Action::Focused(setting) => self.selection.focus(setting == Setting::AccList),
+            Action::CycleUnits => self.units = self.units.next(),
+            Action::SetUnits(units) => self.units = units,
+            // Balances of another chain are not compared with those seen so far.
+            Action::ClearHistory => self.initial.clear(),
+            // Stop watching the selected account if none is given.
+            Action::Unwatch(None) => {
+                let selected = self
+                    .selection
+                    .selected(accounts.len())
+                    .map(|i| &accounts[i]);
+                return Ok(Some(match selected {
+                    Some(acc) => Action::Unwatch(Some(acc.address.clone())),
+                    None => Action::Error("No account selected to unwatch".to_string()),
+                }));
+            }
+            _ => {}
+        }
+        self.interact.update(action)?;
+        // Remember the balance of newly watched accounts.
+        if let Some(accounts) = self.interact.elems.front() {
+            for acc in accounts {
+                self.initial
+                    .entry(acc.address.clone())
+                    .or_insert_with(|| acc.balance.clone());
+            }
+        }
+        Ok(None)
     }
 
     fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        let (columns, _) = self.config.app.layout.columns("AccList", &COLUMNS);
         // Map the relevant list of accounts to rows.
         let rows = {
             // Get the list of accounts currently pointed to.
             if let Some(accounts) = self.interact.get() {
                 // Map accounts to rows.
+                let decimals = self.config.app.decimals;
                 accounts
                     .iter()
                     .map(|acc| {
-                        Row::new(vec![
-                            acc.address.abridged(),
-                            acc.units.clone(),
-                            acc.balance.clone(),
-                        ])
+                        let nonce = if acc.pending_nonce > acc.nonce {
+                            format!("{}/{}", acc.nonce, acc.pending_nonce)
+                        } else {
+                            acc.nonce.to_string()
+                        };
+                        // Describe stuck transactions below the balance.
+                        let mut balance = Text::from(Line::from(balance_spans(
+                            acc,
+                            self.units,
+                            decimals,
+                            &self.config.theme,
+                        )));
+                        if let Some(stuck) = &acc.stuck {
+                            balance.push_line(stuck_line(stuck));
+                        }
+                        let block_delta = match self.previous(&acc.address) {
+                            Some(previous) => delta_span(
+                                &previous,
+                                &acc.balance,
+                                self.units,
+                                decimals,
+                                &self.config.theme,
+                            ),
+                            None => Span::from("-"),
+                        };
+                        let session_delta = match self.initial.get(&acc.address) {
+                            Some(initial) => delta_span(
+                                initial,
+                                &acc.balance,
+                                self.units,
+                                decimals,
+                                &self.config.theme,
+                            ),
+                            None => Span::from("-"),
+                        };
+                        let row = Row::new(pick(
+                            vec![
+                                address_cell(&acc.address, &self.labels, &self.names),
+                                Cell::from(nonce),
+                                Cell::from(balance),
+                                Cell::from(block_delta),
+                                Cell::from(session_delta),
+                                Cell::from(sparkline(&self.history(&acc.address))),
+                            ],
+                            &columns,
+                        ));
+                        if acc.stuck.is_some() {
+                            row.height(2)
+                                .style(self.config.theme.get("acclist.stuck_row"))
+                        } else {
+                            row
+                        }
                     })
                     .collect::<Vec<_>>()
             } else {
@@ -65,26 +286,102 @@ impl Component for AccList {
         };
 
         // Construct the accounts table.
-        let widths = [
-            Constraint::Min(11),         // Address.
-            Constraint::Min(5),          // Units.
-            Constraint::Percentage(100), // Balance.
-        ];
+        let units = self
+            .interact
+            .get()
+            .and_then(|accounts| accounts.first())
+            .map(|acc| self.units.label(&acc.balance.symbol))
+            .unwrap_or_else(|| "BALANCE".to_string());
+        let widths = columns.iter().map(|(_, width)| *width);
         let table = Table::new(rows, widths)
             .column_spacing(2)
-            .style(Style::new().green())
+            .style(self.config.theme.get("acclist.border"))
+            .header(
+                Row::new(pick(
+                    vec![
+                        "ADDRESS".to_string(),
+                        "NONCE".to_string(),
+                        units,
+                        "Δ BLOCK".to_string(),
+                        "Δ SESSION".to_string(),
+                        "HISTORY".to_string(),
+                    ],
+                    &columns,
+                ))
+                .style(self.config.theme.get("acclist.header")),
+            )
             .block(
-                ratatui::widgets::Block::bordered()
+                self.selection
+                    .border(ratatui::widgets::Block::bordered(), &self.config.theme)
                     .title("BALANCES")
-                    .title_alignment(Alignment::Right),
+                    .title_alignment(Alignment::Right)
+                    .title_bottom(if self.selection.focused {
+                        Line::from("j/k: row  Enter: details  Tab: next panel  Esc: unfocus")
+                            .right_aligned()
+                    } else {
+                        Line::default()
+                    }),
             )
-            .row_highlight_style(Style::new().reversed())
-            .column_highlight_style(Style::new().red())
-            .cell_highlight_style(Style::new().blue())
+            .row_highlight_style(self.config.theme.get("acclist.selected_row"))
             .highlight_symbol(">>");
 
-        // Render.
-        frame.render_widget(table, area);
+        // Render, highlighting the selected row while focused.
+        if self.selection.focused {
+            // Leave out the borders and header.
+            self.selection.page = area.height.saturating_sub(3);
+            frame.render_stateful_widget(table, area, &mut self.selection.state);
+        } else {
+            frame.render_widget(table, area);
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::U256;
+    use pretty_assertions::assert_eq;
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::{sparkline, AccList};
+    use crate::components::details::DetailSlot;
+    use crate::{
+        action::Action,
+        components::Component,
+        labels::AddressBook,
+        names::Names,
+        types::{Account, Amount},
+    };
+
+    #[test]
+    fn balance_history() {
+        let (tx, rx) = unbounded_channel();
+        let mut list = AccList::new(
+            rx,
+            AddressBook::default(),
+            Names::default(),
+            DetailSlot::default(),
+        );
+        for balance in [5u64, 7, 3] {
+            tx.send(vec![Account {
+                address: "0xabc".to_string(),
+                balance: Amount::new(U256::from(balance), 0, "ETH"),
+                ..Default::default()
+            }])
+            .unwrap();
+            list.update(Action::Tick).unwrap();
+        }
+        let balance = |value: u64| Amount::new(U256::from(value), 0, "ETH");
+        assert_eq!(list.initial["0xabc"], balance(5));
+        assert_eq!(list.previous("0xabc"), Some(balance(7)));
+        assert_eq!(list.history("0xabc"), [5, 7, 3].map(U256::from));
+        assert_eq!(sparkline(&list.history("0xabc")), "▄█▁");
+        assert_eq!(sparkline(&[U256::from(1); 2]), "▁▁");
+        assert_eq!(sparkline(&[]), "");
+
+        // Selecting an older block shows the history up to that block.
+        list.update(Action::Down).unwrap();
+        assert_eq!(list.previous("0xabc"), Some(balance(5)));
+        assert_eq!(list.history("0xabc"), [5, 7].map(U256::from));
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::VecDeque;
+
+use super::{address_cell, interactive::LIMIT, Component};
+use crate::{
+    action::Action,
+    config::Config,
+    labels::AddressBook,
+    names::Names,
+    types::{Abridged, Transaction, TransactionReceiver},
+    units::Units,
+    watchlist::Watchlist,
+};
+use color_eyre::Result;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Collects transactions touching watched or labelled addresses across every
+/// block seen in the session, newest first.
+#[derive(Default)]
+pub struct Activity {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    labels: AddressBook,
+    names: Names,
+    watchlist: Watchlist,
+    transactions_rx: Option<TransactionReceiver>,
+    transactions: VecDeque<Transaction>,
+    /// Units in which amounts are displayed.
+    units: Units,
+}
+
+impl Activity {
+    pub fn new(
+        transactions_rx: TransactionReceiver,
+        labels: AddressBook,
+        names: Names,
+        watchlist: Watchlist,
+    ) -> Self {
+        Self {
+            labels,
+            names,
+            watchlist,
+            transactions_rx: transactions_rx.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Component for Activity {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.units = config.app.units("Activity");
+        self.config = config;
+        Ok(())
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::CycleUnits => self.units = self.units.next(),
+            Action::SetUnits(units) => self.units = units,
+            _ => {}
+        }
+        // Collect matching transactions regardless of mode so none are missed.
+        if let (Action::Tick, Some(rx)) = (action, self.transactions_rx.as_mut()) {
+            while let Ok(transactions) = rx.try_recv() {
+                for tx in transactions {
+                    if self.watchlist.matches(&tx, &self.labels) {
+                        self.transactions.push_front(tx);
+                    }
+                }
+                self.transactions.truncate(LIMIT);
+            }
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        // Map the matching transactions to rows.
+        let rows = self
+            .transactions
+            .iter()
+            .map(|tx| {
+                Row::new(vec![
+                    Cell::from(tx.block.to_string()),
+                    Cell::from(tx.hash.abridged()),
+                    address_cell(&tx.from, &self.labels, &self.names),
+                    address_cell(&tx.to, &self.labels, &self.names),
+                    Cell::from(
+                        self.units
+                            .format_with_symbol(&tx.value, self.config.app.decimals),
+                    ),
+                ])
+            })
+            .collect::<Vec<_>>();
+
+        // Construct the activity table.
+        let widths = [
+            Constraint::Min(9),  // Block number.
+            Constraint::Min(12), // Hash.
+            Constraint::Fill(1), // From.
+            Constraint::Fill(1), // To.
+            Constraint::Fill(2), // Value.
+        ];
+        let table = Table::new(rows, widths)
+            .column_spacing(1)
+            .style(self.config.theme.get("activity.border"))
+            .header(
+                Row::new(vec!["BLOCK", "HASH", "FROM", "TO", "VALUE"])
+                    .style(self.config.theme.get("activity.header")),
+            )
+            .block(
+                ratatui::widgets::Block::bordered()
+                    .title_bottom(format!("WATCHED ACTIVITY ({})", self.transactions.len()))
+                    .title_alignment(Alignment::Center),
+            );
+
+        // Render.
+        frame.render_widget(table, area);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::Activity;
+    use crate::{
+        action::Action, components::Component, labels::AddressBook, names::Names,
+        types::Transaction, watchlist::Watchlist,
+    };
+
+    #[test]
+    fn collects_matching_transactions() {
+        let (tx, rx) = unbounded_channel();
+        let watchlist = Watchlist::default();
+        watchlist.replace(["0xaaaa".to_string()]);
+        let mut activity = Activity::new(rx, AddressBook::default(), Names::default(), watchlist);
+
+        let transaction = |block: u64, to: &str| Transaction {
+            block,
+            from: "0xcccc".to_string(),
+            to: to.to_string(),
+            ..Default::default()
+        };
+        tx.send(vec![transaction(1, "0xaaaa"), transaction(1, "0xdddd")])
+            .unwrap();
+        tx.send(vec![transaction(2, "0xAAAA")]).unwrap();
+        activity.update(Action::Tick).unwrap();
+
+        let blocks: Vec<_> = activity.transactions.iter().map(|tx| tx.block).collect();
+        assert_eq!(blocks, vec![2, 1]);
+    }
+}
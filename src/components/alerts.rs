@@ -0,0 +1,199 @@
+use std::{
+    collections::VecDeque,
+    io::Write,
+    time::{Duration, Instant},
+};
+
+use super::{interactive::LIMIT, popup_area, Component};
+use crate::{
+    action::Action,
+    alerts::{Alert, AlertReceiver},
+    config::Config,
+};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// How long the notification of new alerts is displayed for.
+const NOTIFICATION_DURATION: Duration = Duration::from_secs(8);
+
+/// Displays a notification when alerts fire and keeps a scrollable history of
+/// all alerts fired in the session.
+#[derive(Default)]
+pub struct Alerts {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    alerts_rx: Option<AlertReceiver>,
+    /// All alerts fired so far, newest first.
+    history: VecDeque<Alert>,
+    /// Number of alerts in the current notification and when it was raised.
+    notification: Option<(usize, Instant)>,
+    /// Whether the history is open.
+    open: bool,
+    state: ListState,
+}
+
+impl Alerts {
+    pub fn new(alerts_rx: AlertReceiver) -> Self {
+        Self {
+            alerts_rx: alerts_rx.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Receives new alerts and raises a notification for them.
+    fn receive(&mut self) {
+        let Some(rx) = self.alerts_rx.as_mut() else {
+            return;
+        };
+        let mut count = 0;
+        while let Ok(alerts) = rx.try_recv() {
+            for alert in alerts {
+                self.history.push_front(alert);
+                count += 1;
+            }
+        }
+        if count == 0 {
+            return;
+        }
+        self.history.truncate(LIMIT);
+        self.notification = Some((count.min(self.history.len()), Instant::now()));
+        if self.config.app.alerts.bell {
+            // Ring the terminal bell.
+            let mut stdout = std::io::stdout();
+            let _ = stdout.write_all(b"\x07").and_then(|_| stdout.flush());
+        }
+    }
+
+    /// Maps alerts to list items.
+    fn items<'a>(alerts: impl Iterator<Item = &'a Alert>) -> Vec<ListItem<'a>> {
+        alerts
+            .map(|alert| {
+                let block = alert
+                    .block
+                    .map(|number| format!("#{number} "))
+                    .unwrap_or_default();
+                ListItem::new(Line::from(vec![
+                    Span::from(alert.time.format("%H:%M:%S ").to_string()).bold(),
+                    Span::from(block),
+                    Span::from(alert.message.as_str()),
+                ]))
+            })
+            .collect()
+    }
+}
+
+impl Component for Alerts {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn is_capturing_keys(&self) -> bool {
+        self.open
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if !self.open {
+            return Ok(None);
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.open = false,
+            KeyCode::Down | KeyCode::Char('j') => self.state.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.state.select_previous(),
+            KeyCode::Home | KeyCode::Char('g') => self.state.select_first(),
+            KeyCode::End | KeyCode::Char('G') => self.state.select_last(),
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::Tick => self.receive(),
+            Action::ToggleAlerts => {
+                self.open = !self.open;
+                self.notification = None;
+                self.state.select_first();
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        if self.open {
+            // Render the history of alerts.
+            let list = List::new(Self::items(self.history.iter()))
+                .style(self.config.theme.get("alerts.border"))
+                .highlight_style(self.config.theme.get("alerts.selected_row"))
+                .block(
+                    ratatui::widgets::Block::bordered()
+                        .title(format!("ALERTS ({})", self.history.len()))
+                        .title_bottom("j/k: scroll  Esc: close"),
+                );
+            let area = popup_area(area, 80, 60);
+            frame.render_widget(Clear, area);
+            frame.render_stateful_widget(list, area, &mut self.state);
+        } else if let Some((count, at)) = self.notification {
+            if at.elapsed() > NOTIFICATION_DURATION {
+                self.notification = None;
+                return Ok(());
+            }
+            // Render a notification of the latest alerts in the top right corner.
+            let shown = count.min(3);
+            let [_, area] =
+                Layout::horizontal([Constraint::Fill(1), Constraint::Percentage(50)]).areas(area);
+            let [area, _] =
+                Layout::vertical([Constraint::Length(shown as u16 + 2), Constraint::Fill(1)])
+                    .areas(area);
+            let list = List::new(Self::items(self.history.iter().take(shown)))
+                .style(self.config.theme.get("alerts.banner"))
+                .block(
+                    ratatui::widgets::Block::bordered()
+                        .title(format!("ALERT ({count})"))
+                        .title_bottom("Shift-a: history"),
+                );
+            frame.render_widget(Clear, area);
+            frame.render_widget(list, area);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::Alerts;
+    use crate::{action::Action, alerts::Alert, components::Component};
+
+    #[test]
+    fn history() {
+        let (tx, rx) = unbounded_channel();
+        let mut alerts = Alerts::new(rx);
+        alerts.config.app.alerts.bell = false;
+        let alert = |message: &str| Alert {
+            message: message.to_string(),
+            block: None,
+            time: chrono::Local::now(),
+        };
+        tx.send(vec![alert("a"), alert("b")]).unwrap();
+        tx.send(vec![alert("c")]).unwrap();
+        alerts.update(Action::Tick).unwrap();
+
+        let messages: Vec<_> = alerts.history.iter().map(|a| a.message.as_str()).collect();
+        assert_eq!(messages, vec!["c", "b", "a"]);
+        assert_eq!(alerts.notification.unwrap().0, 3);
+
+        alerts.update(Action::ToggleAlerts).unwrap();
+        assert!(alerts.is_capturing_keys());
+        assert!(alerts.notification.is_none());
+    }
+}
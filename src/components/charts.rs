@@ -0,0 +1,308 @@
+use super::{
+    amount_f64,
+    interactive::{Interactive, Mode},
+    Component,
+};
+use crate::{
+    action::Action,
+    config::Config,
+    labels::AddressBook,
+    names::Names,
+    types::{Account, AccountReceiver, Block, BlockReceiver},
+};
+use color_eyre::Result;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// A chain metric which can be plotted over the blocks in history.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub enum Metric {
+    #[default]
+    BaseFee,
+    GasUtilisation,
+    TransactionCount,
+    BlockTime,
+    /// Balance of the watched account with the given address, which keeps
+    /// the same account plotted as accounts are watched and unwatched.
+    Balance(String),
+}
+
+/// Plots a metric over the blocks in history, with a cursor at the block
+/// selected in interactive mode.
+#[derive(Default)]
+pub struct Charts {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    labels: AddressBook,
+    names: Names,
+    blocks: Interactive<Block>,
+    accounts: Interactive<Account>,
+    metric: Metric,
+}
+
+impl Charts {
+    pub fn new(
+        block_rx: BlockReceiver,
+        account_rx: AccountReceiver,
+        labels: AddressBook,
+        names: Names,
+    ) -> Self {
+        Self {
+            labels,
+            names,
+            blocks: Interactive {
+                elems_rx: block_rx.into(),
+                ..Default::default()
+            },
+            accounts: Interactive {
+                elems_rx: account_rx.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Selects the next metric, cycling through the balance of each watched
+    /// account after the block metrics.
+    fn next_metric(&mut self) {
+        let accounts = self.accounts.get().map(Vec::as_slice).unwrap_or_default();
+        let balance = |acc: Option<&Account>| acc.map(|acc| Metric::Balance(acc.address.clone()));
+        self.metric = match &self.metric {
+            Metric::BaseFee => Metric::GasUtilisation,
+            Metric::GasUtilisation => Metric::TransactionCount,
+            Metric::TransactionCount => Metric::BlockTime,
+            Metric::BlockTime => balance(accounts.first()).unwrap_or_default(),
+            Metric::Balance(address) => {
+                let next = accounts
+                    .iter()
+                    .position(|acc| &acc.address == address)
+                    .and_then(|i| accounts.get(i + 1));
+                balance(next).unwrap_or_default()
+            }
+        };
+    }
+
+    /// Name of the selected metric, including the account it pertains to.
+    fn title(&self) -> String {
+        match &self.metric {
+            Metric::BaseFee => "BASE FEE (GWEI)".to_string(),
+            Metric::GasUtilisation => "GAS UTILISATION (%)".to_string(),
+            Metric::TransactionCount => "TRANSACTIONS".to_string(),
+            Metric::BlockTime => "BLOCK TIME (S)".to_string(),
+            Metric::Balance(address) => {
+                let name = match self.labels.get(address) {
+                    Some(label) => label.name,
+                    None => self.names.display(address),
+                };
+                let symbol = self
+                    .accounts
+                    .get()
+                    .and_then(|accs| accs.iter().find(|acc| &acc.address == address))
+                    .map(|acc| format!(" ({})", acc.balance.symbol))
+                    .unwrap_or_default();
+                format!("BALANCE {name}{symbol}")
+            }
+        }
+    }
+
+    /// Points of the selected metric by block number, oldest first.
+    fn series(&self) -> Vec<(f64, f64)> {
+        let blocks = self
+            .blocks
+            .elems
+            .iter()
+            .rev()
+            .filter_map(|blocks| blocks.first());
+        match &self.metric {
+            Metric::Balance(address) => {
+                // Balances are retrieved along with each block.
+                let accounts = self.accounts.elems.iter().rev();
+                blocks
+                    .zip(accounts)
+                    .filter_map(|(block, accs)| {
+                        let acc = accs.iter().find(|acc| &acc.address == address)?;
+                        Some((block.number as f64, amount_f64(&acc.balance)))
+                    })
+                    .collect()
+            }
+            metric => blocks
+                .filter_map(|block| {
+                    let value = match *metric {
+                        Metric::BaseFee => block.base_fee? as f64 / 1e9,
+                        Metric::GasUtilisation if block.gas_limit > 0 => {
+                            block.gas_used as f64 / block.gas_limit as f64 * 100.0
+                        }
+                        Metric::TransactionCount => block.transactions as f64,
+                        Metric::BlockTime => block.block_time? as f64,
+                        _ => return None,
+                    };
+                    Some((block.number as f64, value))
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Component for Charts {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        if action == Action::NextMetric {
+            self.next_metric();
+        }
+        self.blocks.update(action.clone())?;
+        self.accounts.update(action)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        let series = self.series();
+        let (x_min, x_max) = bounds(series.iter().map(|(x, _)| *x));
+        let (y_min, y_max) = bounds(series.iter().map(|(_, y)| *y));
+
+        // Draw a vertical line at the selected block while paused.
+        let cursor = match self.blocks.mode {
+            Mode::Interactive => self
+                .blocks
+                .get()
+                .and_then(|blocks| blocks.first())
+                .map(|block| {
+                    let x = block.number as f64;
+                    vec![(x, y_min), (x, y_max)]
+                })
+                .unwrap_or_default(),
+            Mode::Follow => Vec::new(),
+        };
+
+        // Construct the chart.
+        let datasets = vec![
+            Dataset::default()
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(self.config.theme.get("charts.line"))
+                .data(&series),
+            Dataset::default()
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(self.config.theme.get("charts.cursor"))
+                .data(&cursor),
+        ];
+        let label = |value: f64| Span::from(format!("{value:.1}"));
+        let chart = Chart::new(datasets)
+            .style(self.config.theme.get("charts.border"))
+            .block(
+                ratatui::widgets::Block::bordered()
+                    .title(self.title())
+                    .title_bottom("m: next metric"),
+            )
+            .x_axis(
+                Axis::default()
+                    .bounds([x_min, x_max])
+                    .labels([format!("{x_min}"), format!("{x_max}")]),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([y_min, y_max])
+                    .labels([label(y_min), label(y_max)]),
+            );
+
+        // Render.
+        frame.render_widget(chart, area);
+        Ok(())
+    }
+}
+
+/// Returns the range of the values, widened if it would otherwise be empty.
+fn bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let (min, max) = values.fold((f64::MAX, f64::MIN), |(min, max), v| {
+        (min.min(v), max.max(v))
+    });
+    if min > max {
+        (0.0, 1.0)
+    } else if min == max {
+        (min - 1.0, max + 1.0)
+    } else {
+        (min, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::utils::parse_units;
+    use pretty_assertions::assert_eq;
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::{bounds, Charts, Metric};
+    use crate::{
+        action::Action,
+        components::Component,
+        labels::AddressBook,
+        names::Names,
+        types::{Account, Amount, Block},
+    };
+
+    #[test]
+    fn series() {
+        let (block_tx, block_rx) = unbounded_channel();
+        let (account_tx, account_rx) = unbounded_channel();
+        let mut charts = Charts::new(
+            block_rx,
+            account_rx,
+            AddressBook::default(),
+            Names::default(),
+        );
+        for number in 1..=3 {
+            let block = Block {
+                number,
+                base_fee: Some(number * 1_000_000_000),
+                gas_used: 10,
+                gas_limit: 40,
+                ..Default::default()
+            };
+            let account = |address: &str, balance: String| Account {
+                address: address.to_string(),
+                balance: Amount::new(parse_units(&balance, 18).unwrap().into(), 18, "ETH"),
+                ..Default::default()
+            };
+            let mut accounts = vec![account("0xa", format!("{number}.5"))];
+            // An account watched later is listed before the plotted one.
+            if number == 3 {
+                accounts.insert(0, account("0x0", "9".to_string()));
+            }
+            block_tx.send(vec![block]).unwrap();
+            account_tx.send(accounts).unwrap();
+            charts.update(Action::Tick).unwrap();
+        }
+
+        assert_eq!(charts.series(), vec![(1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]);
+        charts.update(Action::NextMetric).unwrap();
+        assert_eq!(charts.metric, Metric::GasUtilisation);
+        assert_eq!(charts.series()[0], (1.0, 25.0));
+
+        // Cycles through the balance of each account back to the base fee.
+        charts.update(Action::NextMetric).unwrap();
+        charts.update(Action::NextMetric).unwrap();
+        charts.update(Action::NextMetric).unwrap();
+        assert_eq!(charts.metric, Metric::Balance("0x0".to_string()));
+        assert_eq!(charts.series(), vec![(3.0, 9.0)]);
+        charts.update(Action::NextMetric).unwrap();
+        assert_eq!(charts.metric, Metric::Balance("0xa".to_string()));
+        assert_eq!(charts.series(), vec![(1.0, 1.5), (2.0, 2.5), (3.0, 3.5)]);
+        charts.update(Action::NextMetric).unwrap();
+        assert_eq!(charts.metric, Metric::BaseFee);
+    }
+
+    #[test]
+    fn empty_bounds() {
+        assert_eq!(bounds([].into_iter()), (0.0, 1.0));
+        assert_eq!(bounds([2.0].into_iter()), (1.0, 3.0));
+        assert_eq!(bounds([2.0, 1.0, 5.0].into_iter()), (1.0, 5.0));
+    }
+}
@@ -0,0 +1,362 @@
+use std::{
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use super::Component;
+use crate::{
+    action::Action, config::Config, labels::AddressBook, units::Units, watchlist::Watchlist,
+};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{prelude::*, widgets::*};
+use strum::VariantNames;
+use tokio::sync::mpsc::UnboundedSender;
+use url::Url;
+
+/// How long the result of a command is displayed for.
+const STATUS_DURATION: Duration = Duration::from_secs(5);
+
+/// Names of the commands and the arguments they take.
+const COMMANDS: [(&str, &str); 7] = [
+    ("goto", "<block>"),
+    ("tx", "<hash>"),
+    ("watch", "<address>"),
+    ("unwatch", "[address]"),
+    ("units", "<units>"),
+    ("endpoint", "<url>"),
+    ("export", "csv [path]"),
+];
+
+/// Command line for actions which take arguments, entered with `:`.
+///
+/// Commands are parsed into actions and sent like those bound to keys.
+/// Commands entered in the session are kept in a history, and names and
+/// arguments are completed with `Tab`.
+#[derive(Default)]
+pub struct CommandLine {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    labels: AddressBook,
+    watchlist: Watchlist,
+    open: bool,
+    input: String,
+    /// Commands entered so far, oldest first.
+    history: Vec<String>,
+    /// Position in the history of the command being recalled, if any.
+    recalled: Option<usize>,
+    /// Completions of the input when completion started, and the one shown.
+    completions: Vec<String>,
+    completion: usize,
+    /// Result of the last command and when it was shown.
+    status: Option<(String, Instant)>,
+}
+
+impl CommandLine {
+    pub fn new(labels: AddressBook, watchlist: Watchlist) -> Self {
+        Self {
+            labels,
+            watchlist,
+            ..Default::default()
+        }
+    }
+
+    /// Parses the input into the action it commands, and records it in the
+    /// history.
+    fn submit(&mut self) -> Option<Action> {
+        self.open = false;
+        let input = std::mem::take(&mut self.input);
+        if input.trim().is_empty() {
+            return None;
+        }
+        if self.history.last() != Some(&input) {
+            self.history.push(input.clone());
+        }
+        Some(parse(&input).unwrap_or_else(Action::Error))
+    }
+
+    /// Replaces the input with an older or newer command from the history.
+    fn recall(&mut self, older: bool) {
+        let recalled = match (self.recalled, older) {
+            (None, true) => self.history.len().checked_sub(1),
+            (None, false) => None,
+            (Some(i), true) => Some(i.saturating_sub(1)),
+            (Some(i), false) => Some(i + 1).filter(|i| *i < self.history.len()),
+        };
+        self.recalled = recalled;
+        self.input = recalled
+            .map(|i| self.history[i].clone())
+            .unwrap_or_default();
+    }
+
+    /// Replaces the input with its next completion.
+    fn complete(&mut self) {
+        if self.completions.is_empty() {
+            self.completions = self.completions();
+            self.completion = 0;
+        } else {
+            self.completion = (self.completion + 1) % self.completions.len();
+        }
+        if let Some(completion) = self.completions.get(self.completion) {
+            self.input = completion.clone();
+        }
+    }
+
+    /// Inputs completing the command name or last argument of the input.
+    fn completions(&self) -> Vec<String> {
+        let Some((name, arg)) = self.input.split_once(' ') else {
+            return COMMANDS
+                .iter()
+                .filter(|(name, _)| name.starts_with(&self.input))
+                .map(|(name, _)| format!("{name} "))
+                .collect();
+        };
+        let candidates = match name {
+            "watch" => self.labels.addresses(),
+            "unwatch" => self.watchlist.addresses(),
+            "units" => Units::VARIANTS.iter().map(ToString::to_string).collect(),
+            "export" => vec!["csv".to_string()],
+            _ => Vec::new(),
+        };
+        candidates
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(arg.trim_start()))
+            .map(|candidate| format!("{name} {candidate}"))
+            .collect()
+    }
+
+    /// Usage of the commands matching the input, shown while typing.
+    fn usage(&self) -> String {
+        let name = self.input.split(' ').next().unwrap_or_default();
+        COMMANDS
+            .iter()
+            .filter(|(command, _)| command.starts_with(name))
+            .map(|(command, args)| format!("{command} {args}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+    }
+}
+
+/// Parses a command into the action it stands for.
+fn parse(input: &str) -> Result<Action, String> {
+    let mut words = input.split_whitespace();
+    let name = words.next().unwrap_or_default();
+    let args = words.collect::<Vec<_>>();
+    let usage = || {
+        COMMANDS
+            .iter()
+            .find(|(command, _)| *command == name)
+            .map(|(command, args)| format!("Usage: :{command} {args}"))
+            .unwrap_or_else(|| format!("Unknown command: {name}"))
+    };
+    let action = match (name, args.as_slice()) {
+        ("goto", [number]) => {
+            let number = number.trim_start_matches('#');
+            Action::GotoBlock(number.parse().map_err(|_| usage())?)
+        }
+        ("tx", [hash]) => Action::ShowTransaction(hash.to_string()),
+        ("watch", [account]) => Action::Watch(account.to_string()),
+        ("unwatch", []) => Action::Unwatch(None),
+        ("unwatch", [account]) => Action::Unwatch(Some(account.to_string())),
+        ("units", [units]) => Action::SetUnits(Units::from_str(units).map_err(|_| {
+            format!(
+                "Unknown units {units}, expected {}",
+                Units::VARIANTS.join(", ")
+            )
+        })?),
+        ("endpoint", [url]) => {
+            let url = Url::parse(url).map_err(|e| format!("Invalid endpoint {url}: {e}"))?;
+            Action::SetEndpoint(url.to_string())
+        }
+        ("export", ["csv"]) => Action::ExportCsv(None),
+        ("export", ["csv", path]) => Action::ExportCsv(Some(path.to_string())),
+        _ => return Err(usage()),
+    };
+    Ok(action)
+}
+
+impl Component for CommandLine {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn is_capturing_keys(&self) -> bool {
+        self.open
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if !self.open {
+            return Ok(None);
+        }
+        if key.code != KeyCode::Tab {
+            self.completions.clear();
+        }
+        match key.code {
+            KeyCode::Esc => {
+                self.open = false;
+                self.input.clear();
+            }
+            KeyCode::Enter => return Ok(self.submit()),
+            KeyCode::Tab => self.complete(),
+            KeyCode::Up => self.recall(true),
+            KeyCode::Down => self.recall(false),
+            // Leave the command line once it is empty, like vim.
+            KeyCode::Backspace if self.input.is_empty() => self.open = false,
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(c) => self.input.push(c),
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::OpenCommandLine => {
+                self.open = true;
+                self.input.clear();
+                self.recalled = None;
+                self.completions.clear();
+                self.status = None;
+            }
+            Action::Status(status) => self.status = Some((status, Instant::now())),
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        let line = if self.open {
+            Line::from(vec![
+                Span::from(format!(":{}", self.input)),
+                Span::from(" ").reversed(),
+                Span::styled(
+                    format!("  {}", self.usage()),
+                    self.config.theme.get("command.usage"),
+                ),
+            ])
+        } else {
+            match &self.status {
+                Some((status, at)) if at.elapsed() < STATUS_DURATION => {
+                    Line::from(status.clone()).italic()
+                }
+                _ => return Ok(()),
+            }
+        };
+        // Render over the bottom line of the screen.
+        let [_, area] = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(line).style(self.config.theme.get("command.line")),
+            area,
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+
+    use super::{parse, CommandLine};
+    use crate::{
+        action::Action, components::Component, labels::AddressBook, units::Units,
+        watchlist::Watchlist,
+    };
+
+    fn press(line: &mut CommandLine, code: KeyCode) -> Option<Action> {
+        line.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+            .unwrap()
+    }
+
+    fn type_str(line: &mut CommandLine, s: &str) {
+        for c in s.chars() {
+            press(line, KeyCode::Char(c));
+        }
+    }
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(parse("goto #123"), Ok(Action::GotoBlock(123)));
+        assert_eq!(
+            parse(" tx 0xabc "),
+            Ok(Action::ShowTransaction("0xabc".into()))
+        );
+        assert_eq!(parse("unwatch"), Ok(Action::Unwatch(None)));
+        assert_eq!(parse("units gwei"), Ok(Action::SetUnits(Units::Gwei)));
+        assert_eq!(
+            parse("endpoint http://localhost:8545"),
+            Ok(Action::SetEndpoint("http://localhost:8545/".into()))
+        );
+        assert_eq!(
+            parse("export csv txs.csv"),
+            Ok(Action::ExportCsv(Some("txs.csv".into())))
+        );
+        assert_eq!(parse("goto"), Err("Usage: :goto <block>".into()));
+        assert_eq!(
+            parse("export json"),
+            Err("Usage: :export csv [path]".into())
+        );
+        assert!(parse("units furlongs").is_err());
+        assert!(parse("endpoint localhost").is_err());
+        assert_eq!(
+            parse("frobnicate"),
+            Err("Unknown command: frobnicate".into())
+        );
+    }
+
+    #[test]
+    fn history_and_completion() {
+        let watchlist = Watchlist::default();
+        watchlist.replace(["0xaaaa".to_string(), "0xbbbb".to_string()]);
+        let mut line = CommandLine::new(AddressBook::default(), watchlist);
+        line.update(Action::OpenCommandLine).unwrap();
+        assert!(line.is_capturing_keys());
+
+        // Command names and arguments are completed, cycling with repeated tabs.
+        type_str(&mut line, "un");
+        press(&mut line, KeyCode::Tab);
+        assert_eq!(line.input, "unwatch ");
+        press(&mut line, KeyCode::Tab);
+        assert_eq!(line.input, "units ");
+        press(&mut line, KeyCode::Tab);
+        assert_eq!(line.input, "unwatch ");
+        press(&mut line, KeyCode::Tab);
+        assert_eq!(line.input, "units ");
+        type_str(&mut line, "g");
+        press(&mut line, KeyCode::Tab);
+        assert_eq!(line.input, "units gwei");
+        assert_eq!(
+            press(&mut line, KeyCode::Enter),
+            Some(Action::SetUnits(Units::Gwei))
+        );
+        assert!(!line.is_capturing_keys());
+
+        line.update(Action::OpenCommandLine).unwrap();
+        type_str(&mut line, "unwatch 0xb");
+        press(&mut line, KeyCode::Tab);
+        assert_eq!(line.input, "unwatch 0xbbbb");
+        press(&mut line, KeyCode::Enter);
+
+        // Earlier commands are recalled, newest first.
+        line.update(Action::OpenCommandLine).unwrap();
+        press(&mut line, KeyCode::Up);
+        assert_eq!(line.input, "unwatch 0xbbbb");
+        press(&mut line, KeyCode::Up);
+        press(&mut line, KeyCode::Up);
+        assert_eq!(line.input, "units gwei");
+        press(&mut line, KeyCode::Down);
+        press(&mut line, KeyCode::Down);
+        assert_eq!(line.input, "");
+        press(&mut line, KeyCode::Esc);
+        assert!(!line.is_capturing_keys());
+    }
+}
@@ -0,0 +1,232 @@
+use std::sync::{Arc, RwLock};
+
+use super::{address_span, fee, popup_area, Component};
+use crate::{
+    action::Action,
+    config::Config,
+    labels::AddressBook,
+    names::Names,
+    types::{Account, Transaction},
+    units::Units,
+};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// A row selected in a list to be shown in full.
+#[derive(Clone, Debug)]
+pub enum Detail {
+    Transaction(Box<Transaction>),
+    Account(Box<Account>),
+}
+
+/// The detail being shown, if any, shared between the lists which open it and
+/// the popup which shows it.
+#[derive(Clone, Debug, Default)]
+pub struct DetailSlot(Arc<RwLock<Option<Detail>>>);
+
+impl DetailSlot {
+    /// Shows the detail in the popup.
+    pub fn open(&self, detail: Detail) {
+        if let Ok(mut slot) = self.0.write() {
+            *slot = Some(detail);
+        }
+    }
+
+    /// Closes the popup.
+    pub fn close(&self) {
+        if let Ok(mut slot) = self.0.write() {
+            *slot = None;
+        }
+    }
+
+    /// Returns the detail being shown, if any.
+    pub fn get(&self) -> Option<Detail> {
+        self.0.read().ok().and_then(|slot| slot.clone())
+    }
+
+    /// Returns true if a detail is being shown.
+    pub fn is_open(&self) -> bool {
+        self.0.read().is_ok_and(|slot| slot.is_some())
+    }
+}
+
+/// Popup showing every field of the transaction or account selected in a list.
+#[derive(Default)]
+pub struct Details {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    labels: AddressBook,
+    names: Names,
+    slot: DetailSlot,
+}
+
+impl Details {
+    pub fn new(slot: DetailSlot, labels: AddressBook, names: Names) -> Self {
+        Self {
+            slot,
+            labels,
+            names,
+            ..Default::default()
+        }
+    }
+
+    /// Lines describing a transaction.
+    fn transaction_lines(&self, tx: &Transaction) -> Vec<Line<'static>> {
+        let decimals = self.config.app.decimals;
+        let mut lines = vec![
+            field("HASH", tx.hash.clone()),
+            field("BLOCK", tx.block.to_string()),
+            field("KIND", tx.kind.to_string()),
+            field("NONCE", tx.nonce.to_string()),
+            self.address_field("FROM", &tx.from),
+            self.address_field("TO", &tx.to),
+            field(
+                "VALUE",
+                Units::Native.format_with_symbol(&tx.value, decimals),
+            ),
+            field("GAS PRICE", fee(tx.gas_price)),
+        ];
+        if let Some(tip) = tx.tip {
+            lines.push(field("TIP", fee(tip)));
+        }
+        if let Some(gas_used) = tx.gas_used {
+            lines.push(field("GAS USED", gas_used.to_string()));
+        }
+        if let Some(blobs) = &tx.blobs {
+            lines.push(field("BLOB GAS", blobs.blob_gas.to_string()));
+            lines.push(field("MAX BLOB FEE", fee(blobs.max_fee_per_blob_gas)));
+            if let Some(cost) = blobs.cost {
+                lines.push(field("BLOB COST", fee(cost)));
+            }
+            for hash in &blobs.versioned_hashes {
+                lines.push(field("BLOB", hash.clone()));
+            }
+        }
+        lines
+    }
+
+    /// Lines describing an account.
+    fn account_lines(&self, acc: &Account) -> Vec<Line<'static>> {
+        let decimals = self.config.app.decimals;
+        let mut lines = vec![
+            self.address_field("ADDRESS", &acc.address),
+            field(
+                "BALANCE",
+                Units::Native.format_with_symbol(&acc.balance, decimals),
+            ),
+            field("NONCE", acc.nonce.to_string()),
+            field("PENDING NONCE", acc.pending_nonce.to_string()),
+        ];
+        if let Some(withdrawn) = &acc.withdrawn {
+            lines.push(field(
+                "WITHDRAWN",
+                Units::Native.format_with_symbol(withdrawn, decimals),
+            ));
+        }
+        if let Some(stuck) = &acc.stuck {
+            lines.push(field("STUCK", format!("{} blocks", stuck.blocks)));
+            if let Some(tx) = &stuck.transaction {
+                lines.push(field("STUCK TX", tx.hash.clone()));
+            }
+        }
+        lines
+    }
+
+    /// A field showing an address in full, followed by its label or name if
+    /// it has one.
+    fn address_field(&self, name: &str, address: &str) -> Line<'static> {
+        let mut line = field(name, address.to_string());
+        if self.labels.get(address).is_some() || self.names.get(address).is_some() {
+            line.push_span(" ");
+            line.push_span(address_span(address, &self.labels, &self.names));
+        }
+        line
+    }
+}
+
+/// A line with the name and value of a field.
+fn field(name: &str, value: String) -> Line<'static> {
+    Line::from(vec![
+        Span::from(format!("{name:>14}: ")).bold(),
+        Span::from(value),
+    ])
+}
+
+impl Component for Details {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn is_capturing_keys(&self) -> bool {
+        self.slot.is_open()
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if matches!(key.code, KeyCode::Esc | KeyCode::Char('q')) {
+            self.slot.close();
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        let (title, lines) = match self.slot.get() {
+            Some(Detail::Transaction(tx)) => ("TRANSACTION", self.transaction_lines(&tx)),
+            Some(Detail::Account(acc)) => ("ACCOUNT", self.account_lines(&acc)),
+            None => return Ok(()),
+        };
+        let paragraph = Paragraph::new(lines)
+            .style(self.config.theme.get("details.border"))
+            .wrap(Wrap { trim: false })
+            .block(
+                ratatui::widgets::Block::bordered()
+                    .title(title)
+                    .title_bottom("Esc: close"),
+            );
+
+        // Render.
+        let area = popup_area(area, 70, 60);
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use super::{Detail, DetailSlot, Details};
+    use crate::{components::Component, labels::AddressBook, names::Names, types::Transaction};
+
+    #[test]
+    fn open_and_close() {
+        let slot = DetailSlot::default();
+        let mut details = Details::new(slot.clone(), AddressBook::default(), Names::default());
+        assert!(!details.is_capturing_keys());
+        let tx = Transaction {
+            hash: "0xabc".to_string(),
+            ..Default::default()
+        };
+        slot.open(Detail::Transaction(Box::new(tx.clone())));
+        assert!(details.is_capturing_keys());
+        assert!(details
+            .transaction_lines(&tx)
+            .iter()
+            .any(|line| line.to_string().contains("0xabc")));
+
+        // Keys other than closing ones leave the popup open.
+        let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        details.handle_key_event(press(KeyCode::Enter)).unwrap();
+        assert!(slot.is_open());
+        details.handle_key_event(press(KeyCode::Esc)).unwrap();
+        assert!(!slot.is_open());
+    }
+}
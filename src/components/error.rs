@@ -1,20 +1,16 @@
 use super::Component;
 use color_eyre::Result;
-use ratatui::{
-    layout::Rect,
-    style::{Color, Style},
-    widgets::Paragraph,
-    Frame,
-};
+use ratatui::{layout::Rect, style::Style, widgets::Paragraph, Frame};
 
 /// Represents an error component in the application.
 pub struct Error {
     message: String,
+    style: Style,
 }
 
 impl Error {
-    pub fn new(message: String) -> Self {
-        Error { message }
+    pub fn new(message: String, style: Style) -> Self {
+        Error { message, style }
     }
 }
 
@@ -22,7 +18,7 @@ impl Component for Error {
     fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
         frame.render_widget(
             Paragraph::new(self.message.clone())
-                .style(Style::default().fg(Color::Red))
+                .style(self.style)
                 .block(ratatui::widgets::Block::bordered().title("Error")),
             area,
         );
@@ -0,0 +1,126 @@
+use super::{fee, Component};
+use crate::{
+    action::Action,
+    config::Config,
+    types::{Fees, FeesReceiver},
+};
+use color_eyre::Result;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Displays the projected base fee of the next block along with priority fee
+/// suggestions for slow, standard and fast inclusion.
+#[derive(Default)]
+pub struct GasOracle {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    fees_rx: Option<FeesReceiver>,
+    /// The latest fee suggestions.
+    fees: Option<Fees>,
+}
+
+impl GasOracle {
+    pub fn new(fees_rx: FeesReceiver) -> Self {
+        Self {
+            fees_rx: fees_rx.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Maps the fee suggestions to rows of priority and max fee per tier.
+    fn rows(fees: &Fees) -> Vec<Row<'static>> {
+        [
+            ("SLOW", fees.slow),
+            ("STANDARD", fees.standard),
+            ("FAST", fees.fast),
+            ("NODE", fees.suggested),
+        ]
+        .into_iter()
+        .map(|(tier, tip)| {
+            // Allow for the base fee doubling over the next few full blocks.
+            let max_fee = fees.next_base_fee.saturating_mul(2).saturating_add(tip);
+            Row::new(vec![tier.to_string(), fee(tip), fee(max_fee)])
+        })
+        .collect()
+    }
+}
+
+impl Component for GasOracle {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        // Always show the latest fees, regardless of mode.
+        if let (Action::Tick, Some(rx)) = (action, self.fees_rx.as_mut()) {
+            while let Ok(fees) = rx.try_recv() {
+                self.fees = Some(fees);
+            }
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        let (title, rows) = match &self.fees {
+            Some(fees) => (
+                format!(
+                    "GAS #{} NEXT BASE {}",
+                    fees.block + 1,
+                    fee(fees.next_base_fee)
+                ),
+                Self::rows(fees),
+            ),
+            None => ("GAS".to_string(), Vec::new()),
+        };
+
+        // Construct the table.
+        let widths = [
+            Constraint::Min(8),  // Tier.
+            Constraint::Fill(1), // Priority fee.
+            Constraint::Fill(1), // Max fee.
+        ];
+        let table = Table::new(rows, widths)
+            .column_spacing(1)
+            .style(self.config.theme.get("gasoracle.border"))
+            .header(
+                Row::new(vec!["TIER", "TIP", "MAX FEE"])
+                    .style(self.config.theme.get("gasoracle.header")),
+            )
+            .block(ratatui::widgets::Block::bordered().title(title));
+
+        // Render.
+        frame.render_widget(table, area);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::GasOracle;
+    use crate::{action::Action, components::Component, types::Fees};
+
+    #[test]
+    fn latest_fees() {
+        let (tx, rx) = unbounded_channel();
+        let mut oracle = GasOracle::new(rx);
+        let fees = |block| Fees {
+            block,
+            next_base_fee: 10_000_000_000,
+            fast: 2_000_000_000,
+            ..Default::default()
+        };
+        tx.send(fees(1)).unwrap();
+        tx.send(fees(2)).unwrap();
+        oracle.update(Action::Tick).unwrap();
+        assert_eq!(oracle.fees.as_ref().unwrap().block, 2);
+        assert_eq!(GasOracle::rows(&fees(2)).len(), 4);
+    }
+}
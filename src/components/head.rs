@@ -1,10 +1,14 @@
 use super::{
+    address_span, fee,
     interactive::{Interactive, Mode},
     Component,
 };
 use crate::{
     action::Action,
     config::Config,
+    labels::AddressBook,
+    names::Names,
+    theme::Theme,
     types::{Block, BlockReceiver},
 };
 use chrono::{DateTime, Utc};
@@ -17,12 +21,16 @@ use tokio::sync::mpsc::UnboundedSender;
 pub struct Head {
     command_tx: Option<UnboundedSender<Action>>,
     config: Config,
+    labels: AddressBook,
+    names: Names,
     interact: Interactive<Block>,
 }
 
 impl Head {
-    pub fn new(block_rx: BlockReceiver) -> Self {
+    pub fn new(block_rx: BlockReceiver, labels: AddressBook, names: Names) -> Self {
         Self {
+            labels,
+            names,
             interact: Interactive {
                 elems_rx: block_rx.into(),
                 ..Default::default()
@@ -44,6 +52,19 @@ impl Component for Head {
     }
 
     fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        // Look up blocks by number for the other panels, which select blocks
+        // by their position in the history.
+        if let Action::GotoBlock(number) = action {
+            let index = self
+                .interact
+                .elems
+                .iter()
+                .position(|blocks| blocks.iter().any(|block| block.number == number));
+            return Ok(Some(match index {
+                Some(index) => Action::SelectBlock(index),
+                None => Action::Error(format!("Block {number} is not in the session history")),
+            }));
+        }
         self.interact.update(action)
     }
 
@@ -94,15 +115,127 @@ impl Component for Head {
         };
         let table = Table::new(rows, widths)
             .column_spacing(2)
-            .style(Style::new().blue())
-            .block(ratatui::widgets::Block::bordered().title(title))
-            .row_highlight_style(Style::new().reversed())
-            .column_highlight_style(Style::new().red())
-            .cell_highlight_style(Style::new().blue())
+            .style(self.config.theme.get("head.border"))
+            .row_highlight_style(self.config.theme.get("head.selected_row"))
             .highlight_symbol(">>");
 
+        // Split the panel into the head row, gas utilisation and block details.
+        let border = ratatui::widgets::Block::bordered().title(title);
+        let inner = border.inner(area);
+        let [row_area, gauge_area, details_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+        ])
+        .areas(inner);
+
         // Render.
-        frame.render_widget(table, area);
+        frame.render_widget(border.style(self.config.theme.get("head.border")), area);
+        frame.render_widget(table, row_area);
+        if let Some(block) = self.interact.get().and_then(|blocks| blocks.first()) {
+            frame.render_widget(gas_gauge(block, &self.config.theme), gauge_area);
+            let details =
+                Paragraph::new(self.details(block)).style(self.config.theme.get("head.border"));
+            frame.render_widget(details, details_area);
+        }
         Ok(())
     }
 }
+
+impl Head {
+    /// Lines describing the fees, contents and producer of a block.
+    fn details(&self, block: &Block) -> Vec<Line<'static>> {
+        let field = |name: &str| Span::from(format!("{name} ")).bold();
+        let none = || "-".to_string();
+        let blob_gas = |gas: Option<u64>| gas.map(|gas| gas.to_string()).unwrap_or_else(none);
+        vec![
+            Line::from(vec![
+                field("BASE FEE"),
+                Span::from(block.base_fee.map(fee).unwrap_or_else(none)),
+                Span::from("  "),
+                field("BLOB GAS"),
+                Span::from(blob_gas(block.blob_gas_used)),
+                Span::from("  "),
+                field("EXCESS"),
+                Span::from(blob_gas(block.excess_blob_gas)),
+            ]),
+            Line::from(match &block.blob_usage {
+                Some(usage) => vec![
+                    field("BLOBS"),
+                    Span::from(format!(
+                        "{} (target {}, max {})",
+                        usage.blobs, usage.target, usage.max
+                    )),
+                    Span::from("  "),
+                    field("BLOB BASE FEE"),
+                    Span::from(fee(usage.base_fee)),
+                ],
+                None => vec![field("BLOBS"), Span::from(none())],
+            }),
+            Line::from(vec![
+                field("TXS"),
+                Span::from(block.transactions.to_string()),
+                Span::from("  "),
+                field("SIZE"),
+                Span::from(block.size.map(kilobytes).unwrap_or_else(none)),
+                Span::from("  "),
+                field("BLOCK TIME"),
+                Span::from(
+                    block
+                        .block_time
+                        .map(|secs| format!("{secs}s"))
+                        .unwrap_or_else(none),
+                ),
+            ]),
+            Line::from(vec![
+                field("RECIPIENT"),
+                address_span(&block.fee_recipient, &self.labels, &self.names),
+                Span::from("  "),
+                field("EXTRA"),
+                Span::from(block.extra_data.clone()),
+            ]),
+        ]
+    }
+}
+
+/// Gauge of the gas used by a block relative to its gas limit.
+fn gas_gauge(block: &Block, theme: &Theme) -> LineGauge<'static> {
+    let ratio = if block.gas_limit == 0 {
+        0.0
+    } else {
+        (block.gas_used as f64 / block.gas_limit as f64).clamp(0.0, 1.0)
+    };
+    LineGauge::default()
+        .filled_style(theme.get("head.gauge_filled"))
+        .unfilled_style(theme.get("head.gauge_unfilled"))
+        .label(format!(
+            "GAS {}/{} ({:.1}%)",
+            millions(block.gas_used),
+            millions(block.gas_limit),
+            ratio * 100.0
+        ))
+        .ratio(ratio)
+}
+
+/// Formats a quantity in millions.
+fn millions(quantity: u64) -> String {
+    format!("{:.1}M", quantity as f64 / 1_000_000.0)
+}
+
+/// Formats a size in bytes as kilobytes.
+fn kilobytes(bytes: u64) -> String {
+    format!("{:.1} KB", bytes as f64 / 1024.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{kilobytes, millions};
+
+    #[test]
+    fn formatting() {
+        assert_eq!(millions(15_000_000), "15.0M");
+        assert_eq!(kilobytes(2048), "2.0 KB");
+    }
+}
@@ -0,0 +1,222 @@
+use super::{popup_area, Component};
+use crate::{
+    action::Action,
+    app::Setting,
+    config::{key_sequence_to_string, Config},
+};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Popup listing the key bindings in effect, and a hint of how a partly typed
+/// key sequence can be completed.
+#[derive(Default)]
+pub struct Help {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    /// Whether the list of key bindings is open.
+    open: bool,
+    /// Lines scrolled past at the top of the list.
+    scroll: u16,
+    /// Setting the keys are currently bound in.
+    setting: Setting,
+    /// Keys typed so far of a sequence not yet bound to an action.
+    pending: Vec<KeyEvent>,
+}
+
+impl Help {
+    /// Lines listing the key bindings of each setting, with keys bound to the
+    /// same action on one line.
+    fn lines(&self) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+        for setting in Setting::ALL {
+            let Some(keymap) = self.config.keybindings.get(&setting) else {
+                continue;
+            };
+            let mut bindings: Vec<(Vec<String>, &Action)> = Vec::new();
+            for (keys, action) in keymap.iter() {
+                let keys = key_sequence_to_string(keys);
+                match bindings.iter_mut().find(|(_, bound)| *bound == action) {
+                    Some((sequences, _)) => sequences.push(keys),
+                    None => bindings.push((vec![keys], action)),
+                }
+            }
+            for (sequences, _) in bindings.iter_mut() {
+                sequences.sort();
+            }
+            bindings.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            let mut title = Span::from(format!("{setting:?}")).bold().underlined();
+            if setting == self.setting {
+                title = title.reversed();
+            }
+            if !lines.is_empty() {
+                lines.push(Line::default());
+            }
+            lines.push(Line::from(title));
+            for (sequences, action) in bindings {
+                lines.push(Line::from(vec![
+                    Span::from(format!("{:>24}  ", sequences.join(", "))).bold(),
+                    Span::from(action.description()),
+                ]));
+            }
+        }
+        lines
+    }
+
+    /// Lines listing the bindings which complete the pending key sequence.
+    fn hint(&self) -> Vec<Line<'static>> {
+        if self.pending.is_empty() {
+            return Vec::new();
+        }
+        self.config
+            .keybindings
+            .continuations(self.setting, &self.pending)
+            .into_iter()
+            .map(|(keys, action)| {
+                Line::from(vec![
+                    Span::from(format!("{keys}  ")).bold(),
+                    Span::from(action.description()),
+                ])
+            })
+            .collect()
+    }
+}
+
+impl Component for Help {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn is_capturing_keys(&self) -> bool {
+        self.open
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if !self.open {
+            return Ok(None);
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('?') => self.open = false,
+            KeyCode::Down | KeyCode::Char('j') => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::Up | KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll = 0,
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::Help => {
+                self.open = !self.open;
+                self.scroll = 0;
+            }
+            Action::Focused(setting) => self.setting = setting,
+            Action::PendingKeys(keys) => self.pending = keys,
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        if self.open {
+            // Render the list of key bindings.
+            let lines = self.lines();
+            let max_scroll = (lines.len() as u16).saturating_sub(1);
+            self.scroll = self.scroll.min(max_scroll);
+            let paragraph = Paragraph::new(lines)
+                .style(self.config.theme.get("help.border"))
+                .scroll((self.scroll, 0))
+                .block(
+                    ratatui::widgets::Block::bordered()
+                        .title("KEY BINDINGS")
+                        .title_bottom("j/k: scroll  Esc: close"),
+                );
+            let area = popup_area(area, 70, 80);
+            frame.render_widget(Clear, area);
+            frame.render_widget(paragraph, area);
+        } else {
+            let hint = self.hint();
+            if hint.is_empty() {
+                return Ok(());
+            }
+            // Render the completions of the pending sequence in the bottom right corner.
+            let [_, area] =
+                Layout::horizontal([Constraint::Fill(1), Constraint::Percentage(40)]).areas(area);
+            let [_, area] = Layout::vertical([
+                Constraint::Fill(1),
+                Constraint::Length(hint.len() as u16 + 2),
+            ])
+            .areas(area);
+            let paragraph = Paragraph::new(hint)
+                .style(self.config.theme.get("help.hint"))
+                .block(
+                    ratatui::widgets::Block::bordered()
+                        .title(key_sequence_to_string(&self.pending)),
+                );
+            frame.render_widget(Clear, area);
+            frame.render_widget(paragraph, area);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+
+    use super::Help;
+    use crate::{
+        action::Action,
+        app::Setting,
+        components::Component,
+        config::{parse_key_sequence, Config},
+    };
+
+    #[test]
+    fn lists_live_bindings() {
+        let mut config = Config::new().unwrap();
+        let keymap = config.keybindings.get_mut(&Setting::TxList).unwrap();
+        keymap.insert(parse_key_sequence("<x><x>").unwrap(), Action::SelectLast);
+        let mut help = Help::default();
+        help.register_config_handler(config).unwrap();
+
+        let lines = help
+            .lines()
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+        assert!(lines.contains(&"TxList".to_string()));
+        // Keys bound to the same action share a line, including overrides.
+        assert!(lines
+            .iter()
+            .any(|line| line.trim_start() == "end, shift-G, x x  Select the last row"));
+        assert!(lines
+            .iter()
+            .any(|line| line.trim_start() == "?  Show key bindings"));
+
+        // Partly typed sequences are completed in the focused setting only.
+        let x = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE);
+        help.update(Action::PendingKeys(vec![x])).unwrap();
+        assert!(help.hint().is_empty());
+        help.update(Action::Focused(Setting::TxList)).unwrap();
+        assert_eq!(help.hint()[0].to_string(), "x x  Select the last row");
+
+        help.update(Action::Help).unwrap();
+        assert!(help.is_capturing_keys());
+        help.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE))
+            .unwrap();
+        assert!(!help.is_capturing_keys());
+    }
+}
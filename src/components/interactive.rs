@@ -5,7 +5,7 @@ use tokio::sync::mpsc::UnboundedReceiver;
 
 /// The maximum number of list elements that can be stored interactive components
 /// at any moment in time.
-const LIMIT: usize = 1000;
+pub const LIMIT: usize = 1000;
 
 #[derive(Default, Clone, Debug)]
 pub enum Mode {
@@ -54,6 +54,11 @@ impl<T> Interactive<T> {
                 self.mode = Mode::Interactive;
                 self.index = self.index.saturating_sub(1);
             }
+            Action::SelectBlock(index) => {
+                // Enter interactive mode and point to the given list of elements.
+                self.mode = Mode::Interactive;
+                self.index = index.min(self.elems.len().saturating_sub(1));
+            }
             Action::Follow => {
                 // Enter follow mode and point to newest list of elements.
                 self.mode = Mode::Follow;
@@ -71,6 +76,12 @@ impl<T> Interactive<T> {
                     }
                 }
             }
+            Action::ClearHistory => {
+                // Start over in follow mode, such as with blocks of another chain.
+                self.elems.clear();
+                self.mode = Mode::Follow;
+                self.index = 0;
+            }
             Action::Render => {}
             _ => {}
         }
@@ -137,5 +148,15 @@ mod tests {
         assert_eq!(interact.index, 0);
         interact.update(Action::Up).unwrap();
         assert_eq!(interact.index, 0);
+        interact.update(Action::SelectBlock(1)).unwrap();
+        assert_eq!(interact.index, 1);
+        interact.update(Action::SelectBlock(5)).unwrap();
+        assert_eq!(interact.index, 2);
+
+        interact.update(Action::ClearHistory).unwrap();
+        assert!(interact.get().is_none());
+        tx.send(vec![10]).unwrap();
+        interact.update(Action::Tick).unwrap();
+        assert_eq!(interact.get().unwrap().clone(), vec![10usize]);
     }
 }
@@ -0,0 +1,229 @@
+use super::{popup_area, Component};
+use crate::{
+    action::Action,
+    labels::{AddressBook, Label},
+};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Names of the fields of the editor, in the order they are filled in.
+const FIELDS: [&str; 3] = ["Address", "Label", "Color"];
+
+/// Popup for adding, editing and removing address labels.
+///
+/// Labels are saved to the labels file once the last field is submitted.
+/// Submitting an empty label removes the label of the address.
+#[derive(Default)]
+pub struct LabelEditor {
+    command_tx: Option<UnboundedSender<Action>>,
+    labels: AddressBook,
+    open: bool,
+    field: usize,
+    inputs: [String; 3],
+}
+
+impl LabelEditor {
+    pub fn new(labels: AddressBook) -> Self {
+        Self {
+            labels,
+            ..Default::default()
+        }
+    }
+
+    /// Opens the editor, prefilled with the existing label of the address if given.
+    pub fn open(&mut self, address: Option<String>) {
+        self.open = true;
+        self.field = 0;
+        self.inputs = Default::default();
+        if let Some(address) = address {
+            self.inputs[0] = address;
+            self.prefill();
+            self.field = 1;
+        }
+    }
+
+    /// Fills in the label fields from the existing label of the address.
+    fn prefill(&mut self) {
+        if let Some(label) = self.labels.get(&self.inputs[0]) {
+            self.inputs[1] = label.name;
+            self.inputs[2] = label.color.unwrap_or_default();
+        }
+    }
+
+    /// Moves to the next field, or saves the label if on the last field.
+    fn submit(&mut self) -> Result<Option<Action>> {
+        match self.field {
+            0 => {
+                let address = self.inputs[0].trim();
+                if !address.starts_with("0x") || address.len() != 42 {
+                    return Ok(Some(Action::Error(format!("Invalid address: {address}"))));
+                }
+                self.inputs[0] = address.to_string();
+                self.prefill();
+                self.field = 1;
+            }
+            1 => self.field = 2,
+            _ => {
+                self.open = false;
+                let [address, name, color] = std::mem::take(&mut self.inputs);
+                let label = (!name.trim().is_empty()).then(|| Label {
+                    name: name.trim().to_string(),
+                    color: (!color.trim().is_empty()).then(|| color.trim().to_string()),
+                });
+                self.labels.set(&address, label);
+                if let Err(e) = self.labels.save() {
+                    return Ok(Some(Action::Error(format!("Failed to save labels: {e}"))));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Component for LabelEditor {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn is_capturing_keys(&self) -> bool {
+        self.open
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if !self.open {
+            return Ok(None);
+        }
+        match key.code {
+            KeyCode::Esc => self.open = false,
+            KeyCode::Enter | KeyCode::Tab => return self.submit(),
+            KeyCode::BackTab => self.field = self.field.saturating_sub(1),
+            KeyCode::Backspace => {
+                self.inputs[self.field].pop();
+            }
+            KeyCode::Char(c) => self.inputs[self.field].push(c),
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        if let Action::EditLabel = action {
+            self.open(None);
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        if !self.open {
+            return Ok(());
+        }
+        let lines = FIELDS
+            .iter()
+            .zip(self.inputs.iter())
+            .enumerate()
+            .map(|(i, (field, input))| {
+                let line = Line::from(vec![
+                    Span::from(format!("{field:>8}: ")).bold(),
+                    Span::from(input.clone()),
+                ]);
+                if i == self.field {
+                    line.reversed()
+                } else {
+                    line
+                }
+            })
+            .collect::<Vec<_>>();
+        let area = popup_area(area, 60, 20);
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(lines).block(
+                ratatui::widgets::Block::bordered()
+                    .title("LABEL")
+                    .title_bottom("Enter: next/save  Esc: cancel"),
+            ),
+            area,
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+
+    use super::LabelEditor;
+    use crate::{
+        action::Action,
+        components::Component,
+        labels::{AddressBook, LabelConfig},
+    };
+
+    fn type_str(editor: &mut LabelEditor, s: &str) {
+        for c in s.chars() {
+            editor
+                .handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn invalid_address() {
+        let mut editor = LabelEditor::default();
+        editor.update(Action::EditLabel).unwrap();
+        assert!(editor.is_capturing_keys());
+        type_str(&mut editor, "0x1234");
+        let action = editor
+            .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .unwrap();
+        assert!(matches!(action, Some(Action::Error(_))));
+        assert_eq!(editor.field, 0);
+
+        editor
+            .handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+            .unwrap();
+        assert!(!editor.is_capturing_keys());
+        assert!(AddressBook::default().get("0x1234").is_none());
+    }
+
+    #[test]
+    fn save_round_trip() {
+        let dir = std::env::temp_dir().join(format!("blockrs-labeleditor-{}", std::process::id()));
+        let config = HashMap::from([(
+            "0xd8da6bf26964af9d7eed9e03e53415d37aa96045".to_string(),
+            LabelConfig::Name("vitalik".to_string()),
+        )]);
+        let address = "0x00000000219ab540356cbb839cbe05303d7705fa";
+        let mut editor = LabelEditor::new(AddressBook::load(&dir, &config).unwrap());
+        editor.update(Action::EditLabel).unwrap();
+        type_str(&mut editor, address);
+        for field in ["deposit", "yellow"] {
+            editor
+                .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+                .unwrap();
+            type_str(&mut editor, field);
+        }
+        let action = editor
+            .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .unwrap();
+        assert_eq!(action, None);
+        assert!(!editor.is_capturing_keys());
+
+        // Only the label edited in-app is saved, not the one from config.
+        let saved = std::fs::read_to_string(dir.join("labels.csv")).unwrap();
+        assert_eq!(
+            saved,
+            format!("address,label,color\n{address},deposit,yellow\n")
+        );
+        let book = AddressBook::load(&dir, &HashMap::new()).unwrap();
+        assert_eq!(book.get(address).unwrap().name, "deposit");
+        assert_eq!(book.addresses(), vec![address.to_string()]);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
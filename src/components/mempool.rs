@@ -0,0 +1,293 @@
+use std::collections::{HashMap, VecDeque};
+
+use super::{address_cell, fee, interactive::LIMIT, popup_area, Component};
+use crate::{
+    action::Action,
+    app::Setting,
+    config::Config,
+    labels::AddressBook,
+    names::Names,
+    types::{Transaction, TransactionReceiver},
+    units::Units,
+    watchlist::Watchlist,
+};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Displays transactions as they enter the transaction pool, newest first, and
+/// marks them once they are included in a block.
+#[derive(Default)]
+pub struct Mempool {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    labels: AddressBook,
+    names: Names,
+    watchlist: Watchlist,
+    pending_rx: Option<TransactionReceiver>,
+    included_rx: Option<TransactionReceiver>,
+    transactions: VecDeque<Transaction>,
+    /// Block numbers of included transactions, by hash.
+    included: HashMap<String, u64>,
+    /// Whether the view is open.
+    open: bool,
+    state: TableState,
+    /// Units in which amounts are displayed.
+    units: Units,
+}
+
+impl Mempool {
+    pub fn new(
+        pending_rx: TransactionReceiver,
+        included_rx: TransactionReceiver,
+        labels: AddressBook,
+        names: Names,
+        watchlist: Watchlist,
+    ) -> Self {
+        Self {
+            labels,
+            names,
+            watchlist,
+            pending_rx: pending_rx.into(),
+            included_rx: included_rx.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Receives newly pending and newly included transactions.
+    fn receive(&mut self) {
+        if let Some(rx) = self.pending_rx.as_mut() {
+            while let Ok(txs) = rx.try_recv() {
+                for tx in txs {
+                    // A transaction which re-enters the pool moves to the top
+                    // rather than being listed twice.
+                    self.transactions.retain(|pending| pending.hash != tx.hash);
+                    self.included.remove(&tx.hash);
+                    self.transactions.push_front(tx);
+                }
+            }
+        }
+        // Forget transactions once there are too many to display.
+        while self.transactions.len() > LIMIT {
+            if let Some(tx) = self.transactions.pop_back() {
+                self.included.remove(&tx.hash);
+            }
+        }
+        if let Some(rx) = self.included_rx.as_mut() {
+            while let Ok(txs) = rx.try_recv() {
+                for tx in txs {
+                    if self
+                        .transactions
+                        .iter()
+                        .any(|pending| pending.hash == tx.hash)
+                    {
+                        self.included.insert(tx.hash, tx.block);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Component for Mempool {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.units = config.app.units("Mempool");
+        self.config = config;
+        Ok(())
+    }
+
+    fn is_capturing_keys(&self) -> bool {
+        self.open
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if !self.open {
+            return Ok(None);
+        }
+        // The view captures keys, so bindings it honours are looked up here.
+        let bound = self.config.keybindings.action(Setting::Default, &[key]);
+        if bound == Some(&Action::CycleUnits) {
+            return Ok(Some(Action::CycleUnits));
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.open = false,
+            KeyCode::Down | KeyCode::Char('j') => self.state.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.state.select_previous(),
+            KeyCode::Home | KeyCode::Char('g') => self.state.select_first(),
+            KeyCode::End | KeyCode::Char('G') => self.state.select_last(),
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::Tick => self.receive(),
+            Action::ToggleMempool => self.open = !self.open,
+            Action::SetUnits(units) => self.units = units,
+            Action::CycleUnits => self.units = self.units.next(),
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        if !self.open {
+            return Ok(());
+        }
+
+        // Map pending transactions to rows.
+        let rows = self
+            .transactions
+            .iter()
+            .map(|tx| {
+                let status = match self.included.get(&tx.hash) {
+                    Some(block) => Cell::from(format!("#{block}"))
+                        .style(self.config.theme.get("mempool.included")),
+                    None => Cell::from("PENDING"),
+                };
+                let row = Row::new(vec![
+                    status,
+                    Cell::from(tx.hash.clone()),
+                    Cell::from(tx.nonce.to_string()),
+                    address_cell(&tx.from, &self.labels, &self.names),
+                    address_cell(&tx.to, &self.labels, &self.names),
+                    Cell::from(fee(tx.gas_price)),
+                    Cell::from(self.units.format(&tx.value, self.config.app.decimals)),
+                ]);
+                // Highlight transactions touching watched or labelled addresses.
+                if self.watchlist.matches(tx, &self.labels) {
+                    row.style(self.config.theme.get("mempool.watched_row"))
+                } else {
+                    row
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Construct the table.
+        let units = self
+            .transactions
+            .front()
+            .map(|tx| self.units.label(&tx.value.symbol))
+            .unwrap_or_default();
+        let widths = [
+            Constraint::Min(10), // Status.
+            Constraint::Fill(7), // Hash.
+            Constraint::Fill(1), // Nonce.
+            Constraint::Fill(2), // From.
+            Constraint::Fill(2), // To.
+            Constraint::Fill(2), // Gas price.
+            Constraint::Fill(3), // Value.
+        ];
+        let title = if self.config.app.mempool {
+            format!("MEMPOOL ({})", self.transactions.len())
+        } else {
+            "MEMPOOL (set `mempool: true` in config to poll pending transactions)".to_string()
+        };
+        let table = Table::new(rows, widths)
+            .column_spacing(2)
+            .style(self.config.theme.get("mempool.border"))
+            .header(
+                Row::new(vec![
+                    "STATUS".to_string(),
+                    "HASH".to_string(),
+                    "NONCE".to_string(),
+                    "FROM".to_string(),
+                    "TO".to_string(),
+                    "GAS PRICE".to_string(),
+                    units,
+                ])
+                .style(self.config.theme.get("mempool.header")),
+            )
+            .block(
+                ratatui::widgets::Block::bordered()
+                    .title(title)
+                    .title_bottom("j/k: scroll  u: units  Esc: close"),
+            )
+            .row_highlight_style(self.config.theme.get("mempool.selected_row"))
+            .highlight_symbol(">>");
+
+        // Render.
+        let area = popup_area(area, 90, 80);
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(table, area, &mut self.state);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::Mempool;
+    use crate::{
+        action::Action,
+        app::Setting,
+        components::Component,
+        config::{parse_key_sequence, Config},
+        labels::AddressBook,
+        names::Names,
+        types::Transaction,
+        watchlist::Watchlist,
+    };
+
+    #[test]
+    fn included() {
+        let (pending_tx, pending_rx) = unbounded_channel();
+        let (included_tx, included_rx) = unbounded_channel();
+        let mut mempool = Mempool::new(
+            pending_rx,
+            included_rx,
+            AddressBook::default(),
+            Names::default(),
+            Watchlist::default(),
+        );
+        let tx = |hash: &str, block: u64| Transaction {
+            hash: hash.to_string(),
+            block,
+            ..Default::default()
+        };
+        pending_tx.send(vec![tx("0x1", 0), tx("0x2", 0)]).unwrap();
+        mempool.update(Action::Tick).unwrap();
+        assert_eq!(mempool.transactions.len(), 2);
+        assert!(mempool.included.is_empty());
+
+        included_tx.send(vec![tx("0x2", 7), tx("0x3", 7)]).unwrap();
+        mempool.update(Action::Tick).unwrap();
+        assert_eq!(mempool.included.get("0x2"), Some(&7));
+        assert!(!mempool.included.contains_key("0x3"));
+
+        // Re-entering the pool, e.g. after a reorg, does not list it twice.
+        pending_tx.send(vec![tx("0x2", 0)]).unwrap();
+        mempool.update(Action::Tick).unwrap();
+        assert_eq!(mempool.transactions.len(), 2);
+        assert_eq!(mempool.transactions[0].hash, "0x2");
+        assert!(!mempool.included.contains_key("0x2"));
+    }
+
+    #[test]
+    fn cycles_units_with_bound_key() {
+        let mut config = Config::new().unwrap();
+        let keymap = config.keybindings.get_mut(&Setting::Default).unwrap();
+        keymap.retain(|_, action| *action != Action::CycleUnits);
+        keymap.insert(parse_key_sequence("<x>").unwrap(), Action::CycleUnits);
+        let mut mempool = Mempool::default();
+        mempool.register_config_handler(config).unwrap();
+        mempool.update(Action::ToggleMempool).unwrap();
+
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        assert_eq!(mempool.handle_key_event(key('u')).unwrap(), None);
+        let action = mempool.handle_key_event(key('x')).unwrap();
+        assert_eq!(action, Some(Action::CycleUnits));
+        let units = mempool.units;
+        mempool.update(Action::CycleUnits).unwrap();
+        assert_eq!(mempool.units, units.next());
+    }
+}
@@ -0,0 +1,113 @@
+use ratatui::widgets::{Block, BorderType, TableState};
+
+use crate::{action::Action, theme::Theme};
+
+/// Selection of a row in the table of a panel, moved by actions while the
+/// panel is focused.
+#[derive(Default)]
+pub struct Selection {
+    pub state: TableState,
+    /// Whether the panel is focused, in which case it responds to selection
+    /// actions.
+    pub focused: bool,
+    /// Number of rows which fit in the table when it was last drawn.
+    pub page: u16,
+}
+
+impl Selection {
+    /// Focuses or unfocuses the panel, selecting the first row when focused
+    /// without a selection.
+    pub fn focus(&mut self, focused: bool) {
+        self.focused = focused;
+        if focused && self.state.selected().is_none() {
+            self.state.select_first();
+        }
+    }
+
+    /// Moves the selection within a table of the given number of rows in
+    /// response to an action, if focused. Returns true if the selected row
+    /// should be opened.
+    pub fn update(&mut self, action: &Action, len: usize) -> bool {
+        if !self.focused {
+            return false;
+        }
+        let last = len.saturating_sub(1);
+        let selected = self.state.selected().unwrap_or_default().min(last);
+        let page = usize::from(self.page.max(1));
+        let selected = match action {
+            Action::OpenDetails => return self.selected(len).is_some(),
+            Action::SelectNext => (selected + 1).min(last),
+            Action::SelectPrevious => selected.saturating_sub(1),
+            Action::SelectPageDown => (selected + page).min(last),
+            Action::SelectPageUp => selected.saturating_sub(page),
+            Action::SelectFirst => 0,
+            Action::SelectLast => last,
+            _ => return false,
+        };
+        self.state.select(Some(selected));
+        false
+    }
+
+    /// Marks the border of the panel while it is focused.
+    pub fn border<'a>(&self, block: Block<'a>, theme: &Theme) -> Block<'a> {
+        if self.focused {
+            block
+                .border_type(BorderType::Thick)
+                .border_style(theme.get("focused_border"))
+        } else {
+            block
+        }
+    }
+
+    /// Index of the selected row in a table of the given number of rows, if
+    /// any.
+    pub fn selected(&self, len: usize) -> Option<usize> {
+        self.state
+            .selected()
+            .filter(|_| len > 0)
+            .map(|selected| selected.min(len - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::Selection;
+    use crate::action::Action;
+
+    #[test]
+    fn moves_within_rows() {
+        let mut selection = Selection {
+            page: 10,
+            ..Default::default()
+        };
+        // Actions are ignored while unfocused.
+        selection.update(&Action::SelectLast, 300);
+        assert_eq!(selection.selected(300), None);
+
+        selection.focus(true);
+        assert_eq!(selection.selected(300), Some(0));
+        selection.update(&Action::SelectPrevious, 300);
+        assert_eq!(selection.selected(300), Some(0));
+        selection.update(&Action::SelectPageDown, 300);
+        selection.update(&Action::SelectNext, 300);
+        assert_eq!(selection.selected(300), Some(11));
+        selection.update(&Action::SelectLast, 300);
+        assert_eq!(selection.selected(300), Some(299));
+        selection.update(&Action::SelectPageDown, 300);
+        assert_eq!(selection.selected(300), Some(299));
+        selection.update(&Action::SelectFirst, 300);
+        assert_eq!(selection.selected(300), Some(0));
+
+        // The selection is kept within shorter lists of rows.
+        selection.update(&Action::SelectLast, 300);
+        assert_eq!(selection.selected(5), Some(4));
+        assert_eq!(selection.selected(0), None);
+
+        assert!(selection.update(&Action::OpenDetails, 5));
+        assert!(!selection.update(&Action::OpenDetails, 0));
+        selection.focus(false);
+        assert!(!selection.update(&Action::OpenDetails, 5));
+    }
+}
@@ -0,0 +1,155 @@
+use super::{address_span, fee, interactive::Interactive, popup_area, Component};
+use crate::{
+    action::Action,
+    config::Config,
+    labels::AddressBook,
+    names::Names,
+    types::{BlockStats, BlockStatsReceiver},
+};
+use color_eyre::Result;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Displays the priority fee distribution, transaction kinds and top gas
+/// consumers of the selected block.
+///
+/// Unlike other popups, the stats do not capture keys so that blocks can be
+/// navigated while they are open.
+#[derive(Default)]
+pub struct Stats {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    labels: AddressBook,
+    names: Names,
+    interact: Interactive<BlockStats>,
+    /// Whether the view is open.
+    open: bool,
+}
+
+impl Stats {
+    pub fn new(stats_rx: BlockStatsReceiver, labels: AddressBook, names: Names) -> Self {
+        Self {
+            labels,
+            names,
+            interact: Interactive {
+                elems_rx: stats_rx.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Lines describing the statistics of a block.
+    fn lines(&self, stats: &BlockStats) -> Vec<Line<'static>> {
+        let heading = |text: &str| Line::from(text.to_string()).bold().italic();
+        let mut lines = vec![heading("PRIORITY FEE")];
+        lines.push(match stats.tips {
+            Some((min, median, max)) => Line::from(format!(
+                "min {}  median {}  max {}",
+                fee(min),
+                fee(median),
+                fee(max)
+            )),
+            None => Line::from("-"),
+        });
+
+        lines.push(Line::default());
+        lines.push(heading("TRANSACTION KINDS"));
+        for (kind, count) in &stats.kinds {
+            let share = *count as f64 / stats.transactions.max(1) as f64 * 100.0;
+            lines.push(Line::from(format!("{kind:<10}{count:>6}  {share:>5.1}%")));
+        }
+
+        lines.push(Line::default());
+        lines.push(heading("TOP GAS CONSUMERS"));
+        if stats.top_consumers.is_empty() {
+            lines.push(Line::from("-"));
+        }
+        for (to, gas_used) in &stats.top_consumers {
+            let to = if to.is_empty() {
+                Span::from("contract creation")
+            } else {
+                address_span(to, &self.labels, &self.names)
+            };
+            lines.push(Line::from(vec![
+                Span::from(format!("{gas_used:>12}  ")),
+                to,
+            ]));
+        }
+        lines
+    }
+}
+
+impl Component for Stats {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        if action == Action::ToggleStats {
+            self.open = !self.open;
+        }
+        self.interact.update(action)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        if !self.open {
+            return Ok(());
+        }
+        let stats = self.interact.get().and_then(|stats| stats.first());
+        let (title, lines) = match stats {
+            Some(stats) => (format!("BLOCK #{} STATS", stats.block), self.lines(stats)),
+            None => ("BLOCK STATS".to_string(), Vec::new()),
+        };
+        let paragraph = Paragraph::new(lines)
+            .style(self.config.theme.get("stats.border"))
+            .block(
+                ratatui::widgets::Block::bordered()
+                    .title(title)
+                    .title_bottom("j/k: block  s: close"),
+            );
+
+        // Render.
+        let area = popup_area(area, 50, 60);
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::Stats;
+    use crate::{
+        action::Action, components::Component, labels::AddressBook, names::Names, types::BlockStats,
+    };
+
+    #[test]
+    fn follows_selected_block() {
+        let (tx, rx) = unbounded_channel();
+        let mut stats = Stats::new(rx, AddressBook::default(), Names::default());
+        for block in 1..=2 {
+            tx.send(vec![BlockStats {
+                block,
+                ..Default::default()
+            }])
+            .unwrap();
+            stats.update(Action::Tick).unwrap();
+        }
+        let selected = |stats: &Stats| stats.interact.get().unwrap()[0].block;
+        assert_eq!(selected(&stats), 2);
+        stats.update(Action::Down).unwrap();
+        assert_eq!(selected(&stats), 1);
+        stats.update(Action::ToggleStats).unwrap();
+        assert!(stats.open);
+        assert!(!stats.is_capturing_keys());
+    }
+}
@@ -1,23 +1,133 @@
-use super::{interactive::Interactive, Component};
+use super::{
+    address_cell, address_span,
+    details::{Detail, DetailSlot},
+    fee,
+    interactive::Interactive,
+    selection::Selection,
+    Component,
+};
 use crate::{
     action::Action,
-    config::Config,
-    types::{Abridged, Transaction, TransactionReceiver},
+    app::Setting,
+    config::{get_data_dir, Config},
+    export::transactions_csv,
+    labels::AddressBook,
+    layout::{pick, Column},
+    names::Names,
+    search::Query,
+    types::{Abridged, Blobs, Transaction, TransactionReceiver},
+    units::Units,
+    watchlist::Watchlist,
 };
 use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{prelude::*, widgets::*};
+use std::path::PathBuf;
 use tokio::sync::mpsc::UnboundedSender;
 
+/// Columns of the transactions table, and their default widths.
+pub const COLUMNS: [Column; 8] = [
+    Column::new("hash", Constraint::Fill(7)),
+    Column::new("kind", Constraint::Fill(1)),
+    Column::new("blobs", Constraint::Min(5)),
+    Column::new("nonce", Constraint::Fill(1)),
+    Column::new("from", Constraint::Fill(2)),
+    Column::new("to", Constraint::Fill(2)),
+    Column::new("value", Constraint::Fill(3)),
+    Column::new("fee", Constraint::Fill(2)),
+];
+
+/// The column transactions are sorted by.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum SortColumn {
+    /// The order of transactions in the block.
+    #[default]
+    Block,
+    Kind,
+    Nonce,
+    From,
+    Value,
+    Fee,
+}
+
+impl SortColumn {
+    /// Returns the column sorted by after this one when cycling.
+    fn next(self) -> Self {
+        match self {
+            Self::Block => Self::Value,
+            Self::Value => Self::Nonce,
+            Self::Nonce => Self::Kind,
+            Self::Kind => Self::From,
+            Self::From => Self::Fee,
+            Self::Fee => Self::Block,
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct TxList {
     command_tx: Option<UnboundedSender<Action>>,
     config: Config,
+    labels: AddressBook,
+    names: Names,
+    watchlist: Watchlist,
     interact: Interactive<Transaction>,
+    /// Whether only transactions touching watched or labelled addresses are shown.
+    filter_watched: bool,
+    /// Units in which amounts are displayed.
+    units: Units,
+    selection: Selection,
+    details: DetailSlot,
+    /// Search typed after `/`, and whether it is still being typed.
+    search: String,
+    searching: bool,
+    /// The parsed search, which is empty if it could not be parsed.
+    query: Query,
+    /// Why the search could not be parsed, if it could not.
+    query_error: Option<String>,
+    /// Whether only transactions matching the search are shown.
+    filter_search: bool,
+    /// Column transactions are sorted by, and whether in descending order.
+    sort: SortColumn,
+    descending: bool,
+    /// Indices of the transactions shown from the selected block, in the
+    /// order they are shown. Kept between updates so that drawing does not
+    /// filter and sort the block again.
+    order: Vec<usize>,
+    /// What the order was computed from.
+    order_inputs: Option<OrderInputs>,
+}
+
+/// What the order of the transactions shown depends on, so that it is only
+/// computed again when one of them changes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct OrderInputs {
+    /// Index of the selected block in the history, with the hash of its first
+    /// transaction and its number of transactions, which tell blocks apart as
+    /// new ones push older ones along.
+    block: (usize, Option<String>, usize),
+    filter_watched: bool,
+    filter_search: bool,
+    query: Query,
+    sort: SortColumn,
+    descending: bool,
+    /// Versions of the labels, names and watchlist.
+    versions: (u64, u64, u64),
 }
 
 impl TxList {
-    pub fn new(transactions_rx: TransactionReceiver) -> Self {
+    pub fn new(
+        transactions_rx: TransactionReceiver,
+        labels: AddressBook,
+        names: Names,
+        watchlist: Watchlist,
+        details: DetailSlot,
+    ) -> Self {
         Self {
+            labels,
+            names,
+            watchlist,
+            details,
             interact: Interactive {
                 elems_rx: transactions_rx.into(),
                 ..Default::default()
@@ -25,6 +135,207 @@ impl TxList {
             ..Default::default()
         }
     }
+
+    /// Transactions of the selected block which pass the filter, in the order
+    /// they are shown.
+    fn visible(&self) -> Vec<&Transaction> {
+        let transactions = self.interact.get().map(Vec::as_slice).unwrap_or_default();
+        self.order
+            .iter()
+            .filter_map(|&i| transactions.get(i))
+            .collect()
+    }
+
+    /// Returns what the order of the transactions shown depends on.
+    fn order_inputs(&self) -> OrderInputs {
+        let transactions = self.interact.get().map(Vec::as_slice).unwrap_or_default();
+        OrderInputs {
+            block: (
+                self.interact.index,
+                transactions.first().map(|tx| tx.hash.clone()),
+                transactions.len(),
+            ),
+            filter_watched: self.filter_watched,
+            filter_search: self.filter_search,
+            query: self.query.clone(),
+            sort: self.sort,
+            descending: self.descending,
+            versions: (
+                self.labels.version(),
+                self.names.version(),
+                self.watchlist.version(),
+            ),
+        }
+    }
+
+    /// Filters and sorts the transactions of the selected block again if the
+    /// block, the filters, the search, the sort or the labels, names or
+    /// watchlist have changed since the last time.
+    fn refresh_order(&mut self) {
+        let inputs = self.order_inputs();
+        if self.order_inputs.as_ref() == Some(&inputs) {
+            return;
+        }
+        self.order_inputs = Some(inputs);
+        let transactions = self.interact.get().map(Vec::as_slice).unwrap_or_default();
+        let mut order = (0..transactions.len())
+            .filter(|&i| {
+                let tx = &transactions[i];
+                (!self.filter_watched || self.watchlist.matches(tx, &self.labels))
+                    && (!self.filter_search || self.is_match(tx))
+            })
+            .collect::<Vec<_>>();
+        // Sorting is stable, and a descending order is reversed both before
+        // and after sorting, so transactions which compare equal stay in
+        // block order.
+        if self.descending {
+            order.reverse();
+        }
+        match self.sort {
+            SortColumn::Block => {}
+            SortColumn::Kind => order.sort_by_key(|&i| transactions[i].kind),
+            SortColumn::Nonce => order.sort_by_key(|&i| transactions[i].nonce),
+            // Senders are sorted as displayed, by label or name if they have
+            // one, looked up once per transaction.
+            SortColumn::From => order.sort_by_cached_key(|&i| {
+                address_span(&transactions[i].from, &self.labels, &self.names).content
+            }),
+            SortColumn::Value => order.sort_by_key(|&i| transactions[i].value.value),
+            SortColumn::Fee => order.sort_by_key(|&i| transactions[i].gas_price),
+        }
+        if self.descending && self.sort != SortColumn::Block {
+            order.reverse();
+        }
+        self.order = order;
+    }
+
+    /// Header of a column, marked with the direction of the sort if sorted
+    /// by it. Block order is only marked when reversed.
+    fn header(&self, name: &str, column: SortColumn) -> String {
+        match (self.sort == column, self.descending) {
+            (true, false) if column != SortColumn::Block => format!("{name} ▲"),
+            (true, true) => format!("{name} ▼"),
+            _ => name.to_string(),
+        }
+    }
+
+    /// Returns true if the transaction matches the search.
+    fn is_match(&self, tx: &Transaction) -> bool {
+        self.query.matches(tx, &self.labels, &self.names)
+    }
+
+    /// Describes the search being typed or kept, with its number of matches.
+    fn search_line(&self) -> Line<'static> {
+        if !self.searching && self.search.is_empty() {
+            return Line::default();
+        }
+        let mut spans = vec![Span::from(format!("/{}", self.search))];
+        if self.searching {
+            spans.push(Span::from(" ").reversed());
+        }
+        match &self.query_error {
+            Some(e) => spans.push(Span::styled(
+                format!(" ({e})"),
+                self.config.theme.get("txlist.search_error"),
+            )),
+            None => {
+                let matches = self.visible().into_iter().filter(|tx| self.is_match(tx));
+                spans.push(Span::from(format!(" ({} matches)", matches.count())));
+                if self.filter_search {
+                    spans.push(Span::from(" FILTERED").bold());
+                }
+            }
+        }
+        Line::from(spans)
+    }
+
+    /// Selects the next or previous transaction shown which matches the
+    /// search, wrapping around, and focuses the list to show it.
+    fn step(&mut self, forward: bool) -> Option<Action> {
+        let matches = self
+            .visible()
+            .iter()
+            .enumerate()
+            .filter(|(_, tx)| self.is_match(tx))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let selected = self.selection.state.selected();
+        let next = if forward {
+            matches
+                .iter()
+                .find(|i| selected.is_none_or(|selected| **i > selected))
+                .or(matches.first())
+        } else {
+            matches
+                .iter()
+                .rev()
+                .find(|i| selected.is_none_or(|selected| **i < selected))
+                .or(matches.last())
+        };
+        self.selection.state.select(Some(*next?));
+        (!self.selection.focused).then_some(Action::FocusTxList)
+    }
+
+    /// Shows the newest transaction in the history whose hash starts with the
+    /// given prefix.
+    fn show(&self, prefix: &str) -> Option<Action> {
+        let prefix = prefix.to_lowercase();
+        let tx = self
+            .interact
+            .elems
+            .iter()
+            .flatten()
+            .find(|tx| tx.hash.to_lowercase().starts_with(&prefix));
+        match tx {
+            Some(tx) => {
+                self.details.open(Detail::Transaction(Box::new(tx.clone())));
+                None
+            }
+            None => Some(Action::Error(format!(
+                "Transaction {prefix} is not in the session history"
+            ))),
+        }
+    }
+
+    /// Exports the transactions shown to a CSV file, by default named after
+    /// the block in the data directory.
+    fn export(&self, path: Option<String>) -> Action {
+        let transactions = self.visible();
+        let Some(block) = transactions.first().map(|tx| tx.block) else {
+            return Action::Error("No transactions to export".to_string());
+        };
+        let path = path
+            .map(PathBuf::from)
+            .unwrap_or_else(|| get_data_dir().join(format!("transactions-{block}.csv")));
+        match transactions_csv(&path, transactions.iter().copied()) {
+            Ok(()) => Action::Status(format!(
+                "Exported {} transactions to {}",
+                transactions.len(),
+                path.display()
+            )),
+            Err(e) => Action::Error(format!("Failed to export transactions: {e}")),
+        }
+    }
+}
+
+/// Describes the blobs of a transaction: their fees and versioned hashes.
+fn blobs_line(blobs: &Blobs) -> Line<'static> {
+    let cost = blobs
+        .cost
+        .map(|cost| format!(", cost {}", fee(cost)))
+        .unwrap_or_default();
+    let hashes = blobs
+        .versioned_hashes
+        .iter()
+        .map(Abridged::abridged)
+        .collect::<Vec<_>>()
+        .join(" ");
+    Line::from(format!(
+        "  max {}/blob gas, {} blob gas{cost}: {hashes}",
+        fee(blobs.max_fee_per_blob_gas),
+        blobs.blob_gas,
+    ))
+    .italic()
 }
 
 impl Component for TxList {
@@ -34,31 +345,119 @@ impl Component for TxList {
     }
 
     fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.units = config.app.units("TxList");
         self.config = config;
         Ok(())
     }
 
+    fn is_capturing_keys(&self) -> bool {
+        self.searching
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if !self.searching {
+            return Ok(None);
+        }
+        match key.code {
+            KeyCode::Esc => {
+                self.searching = false;
+                self.search.clear();
+            }
+            // Keep the search, and go to the first match.
+            KeyCode::Enter => {
+                self.searching = false;
+                self.selection.state.select(None);
+                return Ok(self.step(true));
+            }
+            KeyCode::Backspace => {
+                self.search.pop();
+            }
+            KeyCode::Char(c) => self.search.push(c),
+            _ => {}
+        }
+        (self.query, self.query_error) = match Query::parse(&self.search) {
+            Ok(query) => (query, None),
+            Err(e) => (Query::default(), Some(e)),
+        };
+        self.refresh_order();
+        Ok(None)
+    }
+
     fn update(&mut self, action: Action) -> Result<Option<Action>> {
-        self.interact.update(action)
+        let len = self.visible().len();
+        if self.selection.update(&action, len) {
+            if let Some(i) = self.selection.selected(len) {
+                self.details
+                    .open(Detail::Transaction(Box::new(self.visible()[i].clone())));
+            }
+        }
+        match action {
+            Action::Focused(setting) => self.selection.focus(setting == Setting::TxList),
+            Action::FilterWatched => self.filter_watched = !self.filter_watched,
+            Action::CycleUnits => self.units = self.units.next(),
+            Action::SetUnits(units) => self.units = units,
+            Action::ShowTransaction(ref prefix) => return Ok(self.show(prefix)),
+            Action::ExportCsv(path) => return Ok(Some(self.export(path))),
+            Action::OpenSearch => self.searching = true,
+            Action::SearchNext => return Ok(self.step(true)),
+            Action::SearchPrevious => return Ok(self.step(false)),
+            Action::ToggleSearchFilter => self.filter_search = !self.filter_search,
+            Action::CycleSort => self.sort = self.sort.next(),
+            Action::ReverseSort => self.descending = !self.descending,
+            _ => {}
+        }
+        let action = self.interact.update(action)?;
+        self.refresh_order();
+        Ok(action)
     }
 
     fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        let (columns, _) = self.config.app.layout.columns("TxList", &COLUMNS);
         // Map the relevant list of transactions to rows and get units name for value column.
         let (rows, value_col_name) = {
             // Get the list of transactions currently pointed to.
             if let Some(transactions) = self.interact.get() {
                 // Map transactions to rows.
-                let rows = transactions
-                    .iter()
+                let rows = self
+                    .visible()
+                    .into_iter()
                     .map(|tx| {
-                        Row::new(vec![
-                            tx.hash.clone(),
-                            tx.kind.clone(),
-                            tx.nonce.clone(),
-                            tx.from.abridged(),
-                            tx.to.abridged(),
-                            tx.value.clone(),
-                        ])
+                        // Describe any blobs below the hash.
+                        let mut hash = Text::from(tx.hash.clone());
+                        if let Some(blobs) = &tx.blobs {
+                            hash.push_line(blobs_line(blobs));
+                        }
+                        let row = Row::new(pick(
+                            vec![
+                                Cell::from(hash),
+                                Cell::from(tx.kind.to_string()),
+                                Cell::from(
+                                    tx.blobs
+                                        .as_ref()
+                                        .map(|blobs| blobs.versioned_hashes.len().to_string())
+                                        .unwrap_or_default(),
+                                ),
+                                Cell::from(tx.nonce.to_string()),
+                                address_cell(&tx.from, &self.labels, &self.names),
+                                address_cell(&tx.to, &self.labels, &self.names),
+                                Cell::from(self.units.format(&tx.value, self.config.app.decimals)),
+                                Cell::from(fee(tx.gas_price)),
+                            ],
+                            &columns,
+                        ))
+                        .height(if tx.blobs.is_some() { 2 } else { 1 });
+                        // Highlight transactions matching the search, or touching
+                        // watched or labelled addresses, and mark failed ones.
+                        let theme = &self.config.theme;
+                        if self.is_match(tx) {
+                            row.style(theme.get("txlist.match_row"))
+                        } else if self.watchlist.matches(tx, &self.labels) {
+                            row.style(theme.get("txlist.watched_row"))
+                        } else if tx.failed {
+                            row.style(theme.get("txlist.failed_row"))
+                        } else {
+                            row
+                        }
                     })
                     .collect::<Vec<_>>();
                 // Return rows and units name for the value column.
@@ -66,7 +465,7 @@ impl Component for TxList {
                     rows,
                     transactions
                         .first()
-                        .map(|tx| tx.units.clone().to_uppercase())
+                        .map(|tx| self.units.label(&tx.value.symbol))
                         .unwrap_or_default(),
                 )
             } else {
@@ -74,41 +473,234 @@ impl Component for TxList {
             }
         };
 
-        // Construct the accounts table.
-        let widths = [
-            Constraint::Fill(7), // Hash.
-            Constraint::Fill(1), // Kind.
-            Constraint::Fill(1), // Nonce.
-            Constraint::Fill(2), // From.
-            Constraint::Fill(2), // To.
-            Constraint::Fill(3), // Value.
-        ];
+        // Construct the transactions table.
+        let widths = columns.iter().map(|(_, width)| *width);
         let table = Table::new(rows, widths)
             .column_spacing(2)
-            .style(Style::new().blue())
+            .style(self.config.theme.get("txlist.border"))
             .header(
-                Row::new(vec![
-                    "HASH",
-                    "KIND",
-                    "NONCE",
-                    "FROM",
-                    "TO",
-                    value_col_name.as_str(),
-                ])
-                .style(Style::new().bold().italic()),
+                Row::new(pick(
+                    vec![
+                        self.header("HASH", SortColumn::Block),
+                        self.header("KIND", SortColumn::Kind),
+                        "BLOBS".to_string(),
+                        self.header("NONCE", SortColumn::Nonce),
+                        self.header("FROM", SortColumn::From),
+                        "TO".to_string(),
+                        self.header(&value_col_name, SortColumn::Value),
+                        self.header("FEE", SortColumn::Fee),
+                    ],
+                    &columns,
+                ))
+                .style(self.config.theme.get("txlist.header")),
             )
             .block(
-                ratatui::widgets::Block::bordered()
-                    .title_bottom("TRANSACTIONS")
-                    .title_alignment(Alignment::Center),
+                self.selection
+                    .border(ratatui::widgets::Block::bordered(), &self.config.theme)
+                    .title_bottom(if self.filter_watched {
+                        "TRANSACTIONS (WATCHED)"
+                    } else {
+                        "TRANSACTIONS"
+                    })
+                    .title_alignment(Alignment::Center)
+                    .title_bottom(self.search_line().left_aligned())
+                    .title_bottom(if self.selection.focused {
+                        Line::from("j/k: row  Enter: details  Tab: next panel  Esc: unfocus")
+                            .right_aligned()
+                    } else {
+                        Line::default()
+                    }),
             )
-            .row_highlight_style(Style::new().reversed())
-            .column_highlight_style(Style::new().red())
-            .cell_highlight_style(Style::new().blue())
+            .row_highlight_style(self.config.theme.get("txlist.selected_row"))
             .highlight_symbol(">>");
 
-        // Render.
-        frame.render_widget(table, area);
+        // Render, highlighting the selected row while focused.
+        if self.selection.focused {
+            // Leave out the borders and header.
+            self.selection.page = area.height.saturating_sub(3);
+            frame.render_stateful_widget(table, area, &mut self.selection.state);
+        } else {
+            frame.render_widget(table, area);
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use alloy::primitives::U256;
+
+    use super::TxList;
+    use crate::{
+        action::Action,
+        components::{details::DetailSlot, Component},
+        labels::AddressBook,
+        names::Names,
+        types::{Amount, Transaction, TxKind},
+        watchlist::Watchlist,
+    };
+
+    #[test]
+    fn sort() {
+        let (tx, rx) = unbounded_channel();
+        let mut list = TxList::new(
+            rx,
+            AddressBook::default(),
+            Names::default(),
+            Watchlist::default(),
+            DetailSlot::default(),
+        );
+        let transaction = |hash: &str, value: u64, kind, gas_price| Transaction {
+            hash: hash.to_string(),
+            // Values which would sort differently as text.
+            value: Amount::new(U256::from(value), 18, "ETH"),
+            kind,
+            gas_price,
+            ..Default::default()
+        };
+        tx.send(vec![
+            transaction("0x1", 9, TxKind::Blob, 3),
+            transaction("0x2", 10, TxKind::Legacy, 1),
+            transaction("0x3", 9, TxKind::DynamicFee, 2),
+        ])
+        .unwrap();
+        list.update(Action::Tick).unwrap();
+        let hashes = |list: &TxList| {
+            list.visible()
+                .iter()
+                .map(|tx| tx.hash.as_str())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        assert_eq!(hashes(&list), "0x1 0x2 0x3");
+        assert_eq!(list.header("HASH", super::SortColumn::Block), "HASH");
+
+        // Equal values stay in block order.
+        list.update(Action::CycleSort).unwrap();
+        assert_eq!(hashes(&list), "0x1 0x3 0x2");
+        assert_eq!(list.header("VALUE", super::SortColumn::Value), "VALUE ▲");
+        list.update(Action::ReverseSort).unwrap();
+        assert_eq!(hashes(&list), "0x2 0x1 0x3");
+        assert_eq!(list.header("VALUE", super::SortColumn::Value), "VALUE ▼");
+
+        for _ in 0..4 {
+            list.update(Action::CycleSort).unwrap();
+        }
+        assert_eq!(hashes(&list), "0x1 0x3 0x2");
+        assert_eq!(list.header("FEE", super::SortColumn::Fee), "FEE ▼");
+        list.update(Action::CycleSort).unwrap();
+        assert_eq!(hashes(&list), "0x3 0x2 0x1");
+        assert_eq!(list.header("HASH", super::SortColumn::Block), "HASH ▼");
+
+        // The order is kept for each new block.
+        tx.send(vec![
+            transaction("0x4", 1, TxKind::Legacy, 1),
+            transaction("0x5", 2, TxKind::Legacy, 1),
+        ])
+        .unwrap();
+        list.update(Action::Tick).unwrap();
+        assert_eq!(hashes(&list), "0x5 0x4");
+    }
+
+    #[test]
+    fn sort_follows_names() {
+        let (tx, rx) = unbounded_channel();
+        let names = Names::default();
+        let mut list = TxList::new(
+            rx,
+            AddressBook::default(),
+            names.clone(),
+            Watchlist::default(),
+            DetailSlot::default(),
+        );
+        let transaction = |hash: &str, from: &str| Transaction {
+            hash: hash.to_string(),
+            from: from.to_string(),
+            ..Default::default()
+        };
+        tx.send(vec![
+            transaction("0x1", "0xbbbb"),
+            transaction("0x2", "0xaaaa"),
+        ])
+        .unwrap();
+        list.update(Action::Tick).unwrap();
+        for _ in 0..4 {
+            list.update(Action::CycleSort).unwrap();
+        }
+        assert_eq!(list.sort, super::SortColumn::From);
+        let first = |list: &TxList| list.visible()[0].hash.clone();
+        assert_eq!(first(&list), "0x2");
+
+        // The order is kept while nothing it depends on changes, and sorted
+        // again once a sender is named.
+        let inputs = list.order_inputs.clone();
+        list.update(Action::Render).unwrap();
+        assert_eq!(list.order_inputs, inputs);
+        names.set("0xaaaa", Some("zed.eth".to_string()));
+        list.update(Action::Render).unwrap();
+        assert_eq!(first(&list), "0x1");
+    }
+
+    #[test]
+    fn search() {
+        let (tx, rx) = unbounded_channel();
+        let mut list = TxList::new(
+            rx,
+            AddressBook::default(),
+            Names::default(),
+            Watchlist::default(),
+            DetailSlot::default(),
+        );
+        let transaction = |hash: &str| Transaction {
+            hash: hash.to_string(),
+            ..Default::default()
+        };
+        let hashes = |list: &TxList| {
+            list.visible()
+                .iter()
+                .map(|tx| tx.hash.clone())
+                .collect::<Vec<_>>()
+        };
+        tx.send(vec![
+            transaction("0xaa1"),
+            transaction("0xbb"),
+            transaction("0xaa2"),
+        ])
+        .unwrap();
+        list.update(Action::Tick).unwrap();
+
+        // Typing the search captures keys until it is submitted.
+        list.update(Action::OpenSearch).unwrap();
+        assert!(list.is_capturing_keys());
+        for c in "0xaa".chars() {
+            list.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                .unwrap();
+        }
+        let action = list
+            .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .unwrap();
+        assert!(!list.is_capturing_keys());
+        assert_eq!(action, Some(Action::FocusTxList));
+        assert_eq!(list.selection.state.selected(), Some(0));
+
+        // Stepping between matches wraps around.
+        list.update(Action::SearchNext).unwrap();
+        assert_eq!(list.selection.state.selected(), Some(2));
+        list.update(Action::SearchNext).unwrap();
+        assert_eq!(list.selection.state.selected(), Some(0));
+        list.update(Action::SearchPrevious).unwrap();
+        assert_eq!(list.selection.state.selected(), Some(2));
+
+        // The filter stays active for new blocks.
+        list.update(Action::ToggleSearchFilter).unwrap();
+        assert_eq!(hashes(&list), ["0xaa1", "0xaa2"]);
+        tx.send(vec![transaction("0xcc"), transaction("0xaa3")])
+            .unwrap();
+        list.update(Action::Tick).unwrap();
+        assert_eq!(hashes(&list), ["0xaa3"]);
+    }
+}
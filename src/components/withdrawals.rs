@@ -0,0 +1,180 @@
+use super::{address_cell, interactive::Interactive, popup_area, Component};
+use crate::{
+    action::Action,
+    config::Config,
+    labels::AddressBook,
+    names::Names,
+    types::{Withdrawal, WithdrawalReceiver},
+    units::Units,
+    watchlist::Watchlist,
+};
+use color_eyre::Result;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Displays the withdrawals from validators in the selected block.
+///
+/// Like the block stats, the withdrawals do not capture keys so that blocks
+/// can be navigated while they are open.
+#[derive(Default)]
+pub struct Withdrawals {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    labels: AddressBook,
+    names: Names,
+    watchlist: Watchlist,
+    interact: Interactive<Withdrawal>,
+    /// Whether the view is open.
+    open: bool,
+    /// Units in which amounts are displayed.
+    units: Units,
+}
+
+impl Withdrawals {
+    pub fn new(
+        withdrawal_rx: WithdrawalReceiver,
+        labels: AddressBook,
+        names: Names,
+        watchlist: Watchlist,
+    ) -> Self {
+        Self {
+            labels,
+            names,
+            watchlist,
+            interact: Interactive {
+                elems_rx: withdrawal_rx.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
+
+impl Component for Withdrawals {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.units = config.app.units("Withdrawals");
+        self.config = config;
+        Ok(())
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::ToggleWithdrawals => self.open = !self.open,
+            Action::CycleUnits => self.units = self.units.next(),
+            Action::SetUnits(units) => self.units = units,
+            _ => {}
+        }
+        self.interact.update(action)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        if !self.open {
+            return Ok(());
+        }
+        let withdrawals = self.interact.get().map(Vec::as_slice).unwrap_or_default();
+
+        // Map withdrawals to rows.
+        let rows = withdrawals
+            .iter()
+            .map(|withdrawal| {
+                let row = Row::new(vec![
+                    Cell::from(withdrawal.index.to_string()),
+                    Cell::from(withdrawal.validator.to_string()),
+                    address_cell(&withdrawal.address, &self.labels, &self.names),
+                    Cell::from(
+                        self.units
+                            .format(&withdrawal.amount, self.config.app.decimals),
+                    ),
+                ]);
+                // Highlight withdrawals to watched or labelled addresses.
+                if self
+                    .watchlist
+                    .is_relevant(&withdrawal.address, &self.labels)
+                {
+                    row.style(self.config.theme.get("withdrawals.watched_row"))
+                } else {
+                    row
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Construct the table.
+        let units = withdrawals
+            .first()
+            .map(|withdrawal| self.units.label(&withdrawal.amount.symbol))
+            .unwrap_or_default();
+        let widths = [
+            Constraint::Min(10), // Index.
+            Constraint::Min(10), // Validator index.
+            Constraint::Fill(1), // Address.
+            Constraint::Fill(1), // Amount.
+        ];
+        let table = Table::new(rows, widths)
+            .column_spacing(2)
+            .style(self.config.theme.get("withdrawals.border"))
+            .header(
+                Row::new(vec![
+                    "INDEX".to_string(),
+                    "VALIDATOR".to_string(),
+                    "ADDRESS".to_string(),
+                    units,
+                ])
+                .style(self.config.theme.get("withdrawals.header")),
+            )
+            .block(
+                ratatui::widgets::Block::bordered()
+                    .title(format!("WITHDRAWALS ({})", withdrawals.len()))
+                    .title_bottom("j/k: block  W: close"),
+            );
+
+        // Render.
+        let area = popup_area(area, 70, 70);
+        frame.render_widget(Clear, area);
+        frame.render_widget(table, area);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::Withdrawals;
+    use crate::{
+        action::Action, components::Component, labels::AddressBook, names::Names,
+        types::Withdrawal, units::Units, watchlist::Watchlist,
+    };
+
+    #[test]
+    fn follows_selected_block() {
+        let (tx, rx) = unbounded_channel();
+        let mut withdrawals = Withdrawals::new(
+            rx,
+            AddressBook::default(),
+            Names::default(),
+            Watchlist::default(),
+        );
+        for index in 1..=2 {
+            tx.send(vec![Withdrawal {
+                index,
+                ..Default::default()
+            }])
+            .unwrap();
+            withdrawals.update(Action::Tick).unwrap();
+        }
+        let selected = |withdrawals: &Withdrawals| withdrawals.interact.get().unwrap()[0].index;
+        assert_eq!(selected(&withdrawals), 2);
+        withdrawals.update(Action::Down).unwrap();
+        assert_eq!(selected(&withdrawals), 1);
+        withdrawals.update(Action::ToggleWithdrawals).unwrap();
+        assert!(withdrawals.open);
+        assert!(!withdrawals.is_capturing_keys());
+        withdrawals.update(Action::SetUnits(Units::Gwei)).unwrap();
+        assert_eq!(withdrawals.units, Units::Gwei);
+    }
+}
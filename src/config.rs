@@ -8,9 +8,18 @@ use duration_str::deserialize_duration;
 use lazy_static::lazy_static;
 use ratatui::style::{Color, Modifier, Style};
 use serde::{de::Deserializer, Deserialize};
-use tracing::error;
-
-use crate::{action::Action, app::Setting};
+use tracing::{error, warn};
+
+use crate::{
+    action::Action,
+    alerts::AlertConfig,
+    app::Setting,
+    hooks::HookConfig,
+    labels::LabelConfig,
+    layout::LayoutConfig,
+    theme::{no_color, Theme, ThemeName},
+    units::Units,
+};
 
 const CONFIG: &str = include_str!("../.config/config.json5");
 
@@ -20,6 +29,104 @@ pub struct AppConfig {
     pub addresses: Vec<String>,
     #[serde(default, deserialize_with = "deserialize_duration")]
     pub tick_rate: Duration,
+    #[serde(default)]
+    pub ens: EnsConfig,
+    #[serde(default)]
+    pub labels: HashMap<String, LabelConfig>,
+    #[serde(default)]
+    pub alerts: AlertConfig,
+    #[serde(default)]
+    pub hooks: HookConfig,
+    /// Whether to poll the transaction pool for pending transactions.
+    #[serde(default)]
+    pub mempool: bool,
+    /// Number of blocks for which the pending transactions of a watched
+    /// account can go without inclusion before they are flagged as stuck.
+    #[serde(default = "AppConfig::default_stuck_after")]
+    pub stuck_after: u64,
+    /// Units in which amounts are displayed, by panel.
+    #[serde(default)]
+    pub units: HashMap<String, Units>,
+    /// Number of decimal places shown for amounts.
+    #[serde(default = "AppConfig::default_decimals")]
+    pub decimals: usize,
+    /// How long to wait for the next key of a sequence whose keys so far are
+    /// bound to an action and also start a longer sequence.
+    #[serde(
+        default = "AppConfig::default_key_timeout",
+        deserialize_with = "deserialize_duration"
+    )]
+    pub key_timeout: Duration,
+    /// Which panels are shown and how, and the columns of their tables.
+    #[serde(default)]
+    pub layout: LayoutConfig,
+    /// Built-in theme the configured styles are applied on top of.
+    #[serde(default)]
+    pub theme: Option<ThemeName>,
+}
+
+impl AppConfig {
+    fn default_stuck_after() -> u64 {
+        3
+    }
+
+    fn default_decimals() -> usize {
+        4
+    }
+
+    fn default_key_timeout() -> Duration {
+        Duration::from_secs(1)
+    }
+
+    /// Returns the units in which the given panel displays amounts.
+    pub fn units(&self, panel: &str) -> Units {
+        // Panel names are matched regardless of case.
+        self.units
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(panel))
+            .map(|(_, units)| *units)
+            .unwrap_or_default()
+    }
+}
+
+/// Configuration of ENS name resolution.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EnsConfig {
+    /// Whether to perform reverse lookups of the addresses displayed in the UI.
+    #[serde(default)]
+    pub enabled: bool,
+    /// How long lookup results are cached on disk before being repeated.
+    #[serde(
+        default = "EnsConfig::default_cache_ttl",
+        deserialize_with = "deserialize_duration"
+    )]
+    pub cache_ttl: Duration,
+    /// How often names in the watch list are resolved to addresses.
+    #[serde(
+        default = "EnsConfig::default_refresh_rate",
+        deserialize_with = "deserialize_duration"
+    )]
+    pub refresh_rate: Duration,
+}
+
+impl EnsConfig {
+    fn default_cache_ttl() -> Duration {
+        Duration::from_secs(24 * 60 * 60)
+    }
+
+    fn default_refresh_rate() -> Duration {
+        Duration::from_secs(60 * 60)
+    }
+}
+
+impl Default for EnsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            cache_ttl: Self::default_cache_ttl(),
+            refresh_rate: Self::default_refresh_rate(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize)]
@@ -30,6 +137,9 @@ pub struct Config {
     pub keybindings: KeyBindings,
     #[serde(default)]
     pub styles: Styles,
+    /// Styles of the theme with the configured styles on top.
+    #[serde(skip)]
+    pub theme: Theme,
 }
 
 lazy_static! {
@@ -84,12 +194,7 @@ impl Config {
                     .or_insert_with(|| cmd.clone());
             }
         }
-        for (mode, default_styles) in default_config.styles.iter() {
-            let user_styles = cfg.styles.entry(*mode).or_default();
-            for (style_key, style) in default_styles.iter() {
-                user_styles.entry(style_key.clone()).or_insert(*style);
-            }
-        }
+        cfg.theme = Theme::new(cfg.app.theme, &cfg.styles, no_color());
 
         Ok(cfg)
     }
@@ -124,6 +229,33 @@ fn project_directory() -> Option<ProjectDirs> {
 #[derive(Clone, Debug, Default, Deref, DerefMut)]
 pub struct KeyBindings(pub HashMap<Setting, HashMap<Vec<KeyEvent>, Action>>);
 
+impl KeyBindings {
+    /// Returns the action bound to the key sequence in the given setting,
+    /// falling back to the default bindings.
+    pub fn action(&self, setting: Setting, keys: &[KeyEvent]) -> Option<&Action> {
+        [setting, Setting::Default]
+            .iter()
+            .find_map(|setting| self.get(setting)?.get(keys))
+    }
+
+    /// Returns the bindings in the given setting, including the default
+    /// bindings it does not override, of sequences starting with the given
+    /// keys, sorted by sequence.
+    pub fn continuations(&self, setting: Setting, keys: &[KeyEvent]) -> Vec<(String, &Action)> {
+        let mut bindings = [setting, Setting::Default]
+            .iter()
+            .filter_map(|setting| self.get(setting))
+            .flatten()
+            .filter(|(sequence, _)| sequence.len() > keys.len() && sequence.starts_with(keys))
+            .filter(|(sequence, action)| self.action(setting, sequence) == Some(action))
+            .map(|(sequence, action)| (key_sequence_to_string(sequence), action))
+            .collect::<Vec<_>>();
+        bindings.sort_by(|(a, _), (b, _)| a.cmp(b));
+        bindings.dedup_by(|(a, _), (b, _)| a == b);
+        bindings
+    }
+}
+
 impl<'de> Deserialize<'de> for KeyBindings {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -227,7 +359,6 @@ fn parse_key_code_with_modifiers(
     Ok(KeyEvent::new(c, modifiers))
 }
 
-#[allow(dead_code)]
 pub fn key_event_to_string(key_event: &KeyEvent) -> String {
     let char;
     let key_code = match key_event.code {
@@ -291,6 +422,14 @@ pub fn key_event_to_string(key_event: &KeyEvent) -> String {
     key
 }
 
+/// Formats a sequence of keys for display, e.g. `g g`.
+pub fn key_sequence_to_string(keys: &[KeyEvent]) -> String {
+    keys.iter()
+        .map(key_event_to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 pub fn parse_key_sequence(raw: &str) -> Result<Vec<KeyEvent>, String> {
     if raw.chars().filter(|c| *c == '>').count() != raw.chars().filter(|c| *c == '<').count() {
         return Err(format!("Unable to parse `{}`", raw));
@@ -318,26 +457,48 @@ pub fn parse_key_sequence(raw: &str) -> Result<Vec<KeyEvent>, String> {
     sequences.into_iter().map(parse_key_event).collect()
 }
 
+/// Styles by key, such as `"txlist.border": "bold red"`, applied on top of
+/// those of the theme.
+///
+/// Styles nested by panel, as in `{ "TxList": { "border": "bold red" } }`,
+/// are still accepted and flattened with a warning, and styles which are not
+/// text are ignored with a warning.
 #[derive(Clone, Debug, Default, Deref, DerefMut)]
-pub struct Styles(pub HashMap<Setting, HashMap<String, Style>>);
+pub struct Styles(pub HashMap<String, Style>);
 
 impl<'de> Deserialize<'de> for Styles {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let parsed_map = HashMap::<Setting, HashMap<String, String>>::deserialize(deserializer)?;
-
-        let styles = parsed_map
-            .into_iter()
-            .map(|(mode, inner_map)| {
-                let converted_inner_map = inner_map
-                    .into_iter()
-                    .map(|(str, style)| (str, parse_style(&style)))
-                    .collect();
-                (mode, converted_inner_map)
-            })
-            .collect();
+        let parsed_map = HashMap::<String, serde_json::Value>::deserialize(deserializer)?;
+
+        let mut styles = HashMap::new();
+        for (key, value) in parsed_map {
+            match value {
+                serde_json::Value::String(style) => {
+                    styles.insert(key, parse_style(&style));
+                }
+                serde_json::Value::Object(nested) => {
+                    // Styles of the default panel apply to keys without a prefix.
+                    let prefix = if key.eq_ignore_ascii_case("default") {
+                        String::new()
+                    } else {
+                        format!("{}.", key.to_lowercase())
+                    };
+                    warn!("Flattening styles nested under {key}, which are now keyed like \"{prefix}border\"");
+                    for (nested_key, value) in nested {
+                        match value {
+                            serde_json::Value::String(style) => {
+                                styles.insert(format!("{prefix}{nested_key}"), parse_style(&style));
+                            }
+                            _ => warn!("Ignoring style of {key}.{nested_key}, which is not text"),
+                        }
+                    }
+                }
+                _ => warn!("Ignoring style of {key}, which is not text"),
+            }
+        }
 
         Ok(Styles(styles))
     }
@@ -514,6 +675,76 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_panel_keybindings() -> Result<()> {
+        let c = Config::new()?;
+        let key = |raw| parse_key_sequence(raw).unwrap();
+        assert_eq!(
+            c.keybindings.action(Setting::Default, &key("<j>")),
+            Some(&Action::Down)
+        );
+        assert_eq!(
+            c.keybindings.action(Setting::TxList, &key("<j>")),
+            Some(&Action::SelectNext)
+        );
+        // Falls back to the default bindings.
+        assert_eq!(
+            c.keybindings.action(Setting::AccList, &key("<q>")),
+            Some(&Action::Quit)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ens_config() {
+        let c: AppConfig =
+            json5::from_str(r#"{ "ens": { "enabled": true, "cache_ttl": "1h" } }"#).unwrap();
+        assert!(c.ens.enabled);
+        assert_eq!(c.ens.cache_ttl, Duration::from_secs(3600));
+        assert_eq!(c.ens.refresh_rate, EnsConfig::default_refresh_rate());
+    }
+
+    #[test]
+    fn test_units_config() -> Result<()> {
+        let c: AppConfig = config::Config::builder()
+            .add_source(config::File::from_str(
+                r#"{ "units": { "TxList": "gwei", "accList": "auto" } }"#,
+                config::FileFormat::Json5,
+            ))
+            .build()?
+            .try_deserialize()?;
+        assert_eq!(c.units("TxList"), Units::Gwei);
+        assert_eq!(c.units("AccList"), Units::Auto);
+        assert_eq!(c.units("Mempool"), Units::Native);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_styles() -> Result<()> {
+        let styles: Styles = config::Config::builder()
+            .add_source(config::File::from_str(
+                r#"{
+                    "styles": {
+                        "txlist.border": "red",
+                        "AccList": { "border": "blue", "header": 1 },
+                        "Default": { "focused_border": "bold" },
+                        "head.border": 2,
+                    },
+                }"#,
+                config::FileFormat::Json5,
+            ))
+            .build()?
+            .get("styles")?;
+        assert_eq!(styles["txlist.border"].fg, Some(Color::Indexed(1)));
+        assert_eq!(styles["acclist.border"].fg, Some(Color::Indexed(4)));
+        assert_eq!(
+            styles["focused_border"],
+            Style::default().add_modifier(Modifier::BOLD)
+        );
+        assert_eq!(styles.len(), 3);
+        Ok(())
+    }
+
     #[test]
     fn test_simple_keys() {
         assert_eq!(
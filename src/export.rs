@@ -0,0 +1,88 @@
+use std::path::Path;
+
+use alloy::primitives::utils::format_units;
+use color_eyre::Result;
+use serde::Serialize;
+
+use crate::types::Transaction;
+
+/// A row of an exported CSV file of transactions. Amounts are in the smallest
+/// unit of the native currency, except for the value.
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    block: u64,
+    hash: &'a str,
+    kind: String,
+    from: &'a str,
+    to: &'a str,
+    nonce: u64,
+    value: String,
+    symbol: &'a str,
+    gas_price: u128,
+    gas_used: Option<u64>,
+}
+
+impl<'a> From<&'a Transaction> for Record<'a> {
+    fn from(tx: &'a Transaction) -> Self {
+        Self {
+            block: tx.block,
+            hash: &tx.hash,
+            kind: tx.kind.to_string(),
+            from: &tx.from,
+            to: &tx.to,
+            nonce: tx.nonce,
+            value: format_units(tx.value.value, tx.value.decimals).unwrap_or_default(),
+            symbol: &tx.value.symbol,
+            gas_price: tx.gas_price,
+            gas_used: tx.gas_used,
+        }
+    }
+}
+
+/// Writes transactions to a CSV file, creating its directory if needed.
+pub fn transactions_csv<'a>(
+    path: &Path,
+    transactions: impl IntoIterator<Item = &'a Transaction>,
+) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut writer = csv::Writer::from_path(path)?;
+    for tx in transactions {
+        writer.serialize(Record::from(tx))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::U256;
+    use pretty_assertions::assert_eq;
+
+    use super::transactions_csv;
+    use crate::types::{Amount, Transaction};
+
+    #[test]
+    fn export_transactions() {
+        let path = std::env::temp_dir()
+            .join(format!("blockrs-export-{}", std::process::id()))
+            .join("txs.csv");
+        let tx = Transaction {
+            block: 7,
+            hash: "0xabc".to_string(),
+            from: "0x1".to_string(),
+            value: Amount::new(U256::from(1_500_000_000_000_000_000u64), 18, "ETH"),
+            gas_price: 2,
+            ..Default::default()
+        };
+        transactions_csv(&path, [&tx]).unwrap();
+        let csv = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            csv,
+            "block,hash,kind,from,to,nonce,value,symbol,gas_price,gas_used\n\
+             7,0xabc,Legacy,0x1,,0,1.500000000000000000,ETH,2,\n"
+        );
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
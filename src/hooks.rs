@@ -0,0 +1,358 @@
+use std::{process::Stdio, sync::Arc, time::Duration};
+
+use alloy::primitives::utils::format_units;
+use color_eyre::{eyre::eyre, Result};
+use duration_str::deserialize_duration;
+use serde::{Deserialize, Serialize};
+use tokio::{io::AsyncWriteExt, process::Command, sync::Semaphore};
+use tracing::{debug, error, warn};
+
+use crate::{
+    alerts::Alert,
+    types::{Account, Amount, Block},
+};
+
+/// Configuration of hooks.
+#[derive(Clone, Debug, Deserialize)]
+pub struct HookConfig {
+    /// Maximum number of hooks running at once. Hooks triggered while this
+    /// many are running are skipped with a warning, so that slow hooks do not
+    /// pile up.
+    #[serde(default = "HookConfig::default_max_concurrency")]
+    pub max_concurrency: usize,
+    #[serde(default)]
+    pub hooks: Vec<Hook>,
+}
+
+impl HookConfig {
+    fn default_max_concurrency() -> usize {
+        4
+    }
+}
+
+impl Default for HookConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrency: Self::default_max_concurrency(),
+            hooks: Vec::new(),
+        }
+    }
+}
+
+/// An automation triggered by events in the app.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Hook {
+    /// The kinds of events which trigger the hook.
+    pub events: Vec<EventKind>,
+    #[serde(flatten)]
+    pub target: HookTarget,
+    /// Maximum duration of the hook, after which it is aborted.
+    #[serde(
+        default = "Hook::default_timeout",
+        deserialize_with = "deserialize_duration"
+    )]
+    pub timeout: Duration,
+}
+
+impl Hook {
+    fn default_timeout() -> Duration {
+        Duration::from_secs(10)
+    }
+}
+
+/// What a hook does with the JSON representation of an event.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookTarget {
+    /// Runs a shell command with the event on stdin.
+    Exec(String),
+    /// Sends the event in the body of a POST request.
+    Webhook(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    NewBlock,
+    BalanceChanged,
+    Alert,
+}
+
+/// An event which can trigger hooks, serialized as JSON for the hook.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum HookEvent {
+    NewBlock {
+        number: u64,
+        hash: String,
+        timestamp: u64,
+        base_fee: Option<u64>,
+        transactions: usize,
+    },
+    BalanceChanged {
+        block: u64,
+        address: String,
+        previous: String,
+        balance: String,
+        units: String,
+    },
+    Alert {
+        block: Option<u64>,
+        message: String,
+    },
+}
+
+impl HookEvent {
+    pub fn new_block(block: &Block, transactions: usize) -> Self {
+        Self::NewBlock {
+            number: block.number,
+            hash: block.hash.clone(),
+            timestamp: block.timestamp,
+            base_fee: block.base_fee,
+            transactions,
+        }
+    }
+
+    pub fn balance_changed(block: u64, previous: &Account, account: &Account) -> Self {
+        let format = |balance: &Amount| {
+            format_units(balance.value, balance.decimals)
+                .unwrap_or_else(|_| balance.value.to_string())
+        };
+        Self::BalanceChanged {
+            block,
+            address: account.address.clone(),
+            previous: format(&previous.balance),
+            balance: format(&account.balance),
+            units: account.balance.symbol.clone(),
+        }
+    }
+
+    pub fn kind(&self) -> EventKind {
+        match self {
+            Self::NewBlock { .. } => EventKind::NewBlock,
+            Self::BalanceChanged { .. } => EventKind::BalanceChanged,
+            Self::Alert { .. } => EventKind::Alert,
+        }
+    }
+}
+
+impl From<&Alert> for HookEvent {
+    fn from(alert: &Alert) -> Self {
+        Self::Alert {
+            block: alert.block,
+            message: alert.message.clone(),
+        }
+    }
+}
+
+/// Runs hooks in the background so that the caller is never blocked by them.
+#[derive(Clone, Debug)]
+pub struct HookRunner {
+    hooks: Arc<Vec<Hook>>,
+    permits: Arc<Semaphore>,
+    client: reqwest::Client,
+}
+
+impl HookRunner {
+    pub fn new(config: HookConfig) -> Self {
+        Self {
+            hooks: Arc::new(config.hooks),
+            permits: Arc::new(Semaphore::new(config.max_concurrency.max(1))),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Triggers the hooks for an event, returning how many were started.
+    /// Hooks are skipped if too many are already running. Failures are
+    /// logged.
+    pub fn dispatch(&self, event: HookEvent) -> usize {
+        let kind = event.kind();
+        let hooks = self.hooks.iter().filter(|hook| hook.events.contains(&kind));
+        let mut started = 0;
+        for hook in hooks.cloned() {
+            let Ok(permit) = self.permits.clone().try_acquire_owned() else {
+                warn!(
+                    "Skipped {:?} hook for {kind:?}, too many hooks are running",
+                    hook.target
+                );
+                continue;
+            };
+            let event = event.clone();
+            let client = self.client.clone();
+            started += 1;
+            tokio::spawn(async move {
+                let _permit = permit;
+                match run(&client, &hook, &event).await {
+                    Ok(()) => debug!("Ran {:?} hook for {kind:?}", hook.target),
+                    Err(e) => error!("Failed to run {:?} hook for {kind:?}: {e}", hook.target),
+                }
+            });
+        }
+        started
+    }
+}
+
+/// Runs a single hook for an event, aborting it if it exceeds its timeout.
+async fn run(client: &reqwest::Client, hook: &Hook, event: &HookEvent) -> Result<()> {
+    let payload = serde_json::to_vec(event)?;
+    let result = tokio::time::timeout(hook.timeout, async {
+        match &hook.target {
+            HookTarget::Exec(command) => {
+                let mut child = Command::new("sh")
+                    .arg("-c")
+                    .arg(command)
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .kill_on_drop(true)
+                    .spawn()?;
+                if let Some(mut stdin) = child.stdin.take() {
+                    stdin.write_all(&payload).await?;
+                }
+                let status = child.wait().await?;
+                if !status.success() {
+                    return Err(eyre!("command exited with {status}"));
+                }
+            }
+            HookTarget::Webhook(url) => {
+                client
+                    .post(url.as_str())
+                    .header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .body(payload)
+                    .send()
+                    .await?
+                    .error_for_status()?;
+            }
+        }
+        Ok(())
+    })
+    .await;
+    result.map_err(|_| eyre!("timed out after {:?}", hook.timeout))?
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::{run, EventKind, Hook, HookConfig, HookEvent, HookRunner, HookTarget};
+
+    fn event() -> HookEvent {
+        HookEvent::Alert {
+            block: Some(1),
+            message: "hello".to_string(),
+        }
+    }
+
+    fn hook(target: HookTarget, timeout: Duration) -> Hook {
+        Hook {
+            events: vec![EventKind::Alert],
+            target,
+            timeout,
+        }
+    }
+
+    #[test]
+    fn parse_config() {
+        let config: HookConfig = json5::from_str(
+            r#"{
+                hooks: [
+                    { events: ["new_block", "alert"], exec: "cat", timeout: "1s" },
+                    { events: ["balance_changed"], webhook: "http://localhost:8080/hook" },
+                ],
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(config.max_concurrency, 4);
+        assert_eq!(
+            config.hooks[0].events,
+            vec![EventKind::NewBlock, EventKind::Alert]
+        );
+        assert!(matches!(config.hooks[0].target, HookTarget::Exec(ref c) if c == "cat"));
+        assert!(matches!(config.hooks[1].target, HookTarget::Webhook(_)));
+        assert_eq!(config.hooks[1].timeout, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn serialize_event() {
+        assert_eq!(
+            serde_json::to_string(&event()).unwrap(),
+            r#"{"event":"alert","block":1,"message":"hello"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn exec() {
+        let path = std::env::temp_dir().join(format!("blockrs-hook-{}", std::process::id()));
+        let command = format!("cat > {}", path.display());
+        let hook = hook(HookTarget::Exec(command), Duration::from_secs(5));
+        run(&reqwest::Client::new(), &hook, &event()).await.unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(written, serde_json::to_string(&event()).unwrap());
+        std::fs::remove_file(path).unwrap();
+
+        let hook = super::Hook {
+            target: HookTarget::Exec("exit 1".to_string()),
+            ..hook
+        };
+        assert!(run(&reqwest::Client::new(), &hook, &event()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn exec_timeout() {
+        let hook = hook(
+            HookTarget::Exec("sleep 5".to_string()),
+            Duration::from_millis(100),
+        );
+        let e = run(&reqwest::Client::new(), &hook, &event()).await;
+        assert!(e.unwrap_err().to_string().contains("timed out"));
+    }
+
+    #[tokio::test]
+    async fn skips_hooks_over_concurrency() {
+        let runner = HookRunner::new(HookConfig {
+            max_concurrency: 1,
+            hooks: vec![hook(
+                HookTarget::Exec("true".to_string()),
+                Duration::from_secs(5),
+            )],
+        });
+        // Skipped rather than queued while another hook holds the only permit.
+        let permit = runner.permits.clone().try_acquire_owned().unwrap();
+        assert_eq!(runner.dispatch(event()), 0);
+        drop(permit);
+        assert_eq!(runner.dispatch(event()), 1);
+    }
+
+    #[tokio::test]
+    async fn webhook() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            // Read until the whole body has been received.
+            while !String::from_utf8_lossy(&request).ends_with('}') {
+                let n = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let hook = hook(HookTarget::Webhook(url), Duration::from_secs(5));
+        run(&reqwest::Client::new(), &hook, &event()).await.unwrap();
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /hook"));
+        assert!(request.ends_with(&serde_json::to_string(&event()).unwrap()));
+    }
+}
@@ -0,0 +1,93 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::KeyEvent;
+
+use crate::{action::Action, app::Setting, config::KeyBindings};
+
+/// Keys typed so far of a sequence bound to an action, matched against the
+/// key bindings as each key is typed.
+///
+/// A sequence which is also the prefix of a longer one, like `g` when `g g`
+/// is bound too, waits for the next key until it times out.
+#[derive(Debug, Default)]
+pub struct KeySequence {
+    keys: Vec<KeyEvent>,
+    /// Length of the longest prefix of the keys bound to an action, and the
+    /// action, performed if the sequence is not completed.
+    bound: Option<(usize, Action)>,
+    /// When the last key of the sequence was typed.
+    typed: Option<Instant>,
+}
+
+impl KeySequence {
+    /// Keys typed so far of a sequence not yet performed.
+    pub fn keys(&self) -> &[KeyEvent] {
+        &self.keys
+    }
+
+    /// Adds a typed key to the sequence, returning the actions it completes.
+    ///
+    /// Keys which cannot extend the sequence complete the longest prefix
+    /// bound to an action and start a new sequence.
+    pub fn push(
+        &mut self,
+        key: KeyEvent,
+        keybindings: &KeyBindings,
+        setting: Setting,
+        now: Instant,
+    ) -> Vec<Action> {
+        let mut keys = std::mem::take(&mut self.keys);
+        keys.push(key);
+        let bound = keybindings.action(setting, &keys).cloned();
+        if !keybindings.continuations(setting, &keys).is_empty() {
+            // Wait for the sequence to be extended.
+            if let Some(action) = bound {
+                self.bound = Some((keys.len(), action));
+            }
+            self.keys = keys;
+            self.typed = Some(now);
+            return Vec::new();
+        }
+        if let Some(action) = bound {
+            self.clear();
+            return vec![action];
+        }
+        let mut actions = Vec::new();
+        let retried = match self.bound.take() {
+            // Perform the bound prefix and type the keys after it afresh.
+            Some((len, action)) => {
+                actions.push(action);
+                keys.split_off(len)
+            }
+            // Abandon the sequence and type the key afresh.
+            None if keys.len() > 1 => vec![key],
+            None => Vec::new(),
+        };
+        self.clear();
+        for key in retried {
+            actions.extend(self.push(key, keybindings, setting, now));
+        }
+        actions
+    }
+
+    /// Returns true if the sequence has waited longer than the timeout for
+    /// its next key.
+    pub fn is_expired(&self, timeout: Duration, now: Instant) -> bool {
+        self.typed
+            .is_some_and(|typed| now.saturating_duration_since(typed) >= timeout)
+    }
+
+    /// Ends the sequence, returning the action bound to its longest prefix,
+    /// if any.
+    pub fn take(&mut self) -> Option<Action> {
+        let bound = self.bound.take();
+        self.clear();
+        bound.map(|(_, action)| action)
+    }
+
+    fn clear(&mut self) {
+        self.keys.clear();
+        self.bound = None;
+        self.typed = None;
+    }
+}
@@ -0,0 +1,316 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+};
+
+use color_eyre::{eyre::eyre, Result};
+use ratatui::style::Style;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::config::parse_style;
+
+/// Name of the file in the config directory containing the address book.
+const LABELS_FILE: &str = "labels.csv";
+
+/// A user-defined label for an address.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Label {
+    pub name: String,
+    /// Style in which the label is rendered, e.g. `bold yellow`.
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+impl Label {
+    /// Returns the style in which the label should be rendered.
+    pub fn style(&self) -> Style {
+        self.color.as_deref().map(parse_style).unwrap_or_default()
+    }
+}
+
+/// A label as it can be specified in the config file, either as a plain name
+/// or with a color.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum LabelConfig {
+    Name(String),
+    Label(Label),
+}
+
+impl From<LabelConfig> for Label {
+    fn from(label: LabelConfig) -> Self {
+        match label {
+            LabelConfig::Name(name) => Label { name, color: None },
+            LabelConfig::Label(label) => label,
+        }
+    }
+}
+
+/// A row of the labels file.
+#[derive(Debug, Serialize, Deserialize)]
+struct Record {
+    address: String,
+    label: String,
+    #[serde(default)]
+    color: Option<String>,
+}
+
+/// Labels of addresses, shared between the components that display them.
+///
+/// Labels come from the `labels` section of the config and the labels file in
+/// the config directory. Labels added in-app are persisted to the labels file.
+#[derive(Clone, Debug, Default)]
+pub struct AddressBook {
+    path: PathBuf,
+    labels: Arc<RwLock<Labels>>,
+    /// Bumped whenever a label is set or cleared.
+    version: Arc<AtomicU64>,
+}
+
+/// Labels by lowercase address, kept apart by where they came from so that
+/// only those of the labels file are saved to it.
+#[derive(Debug, Default)]
+struct Labels {
+    config: HashMap<String, Label>,
+    /// Labels of the labels file, which take precedence over config. Labels
+    /// from config which were removed are kept as none, and saved as records
+    /// with an empty label.
+    file: HashMap<String, Option<Label>>,
+}
+
+impl Labels {
+    fn get(&self, address: &str) -> Option<&Label> {
+        match self.file.get(address) {
+            Some(label) => label.as_ref(),
+            None => self.config.get(address),
+        }
+    }
+}
+
+impl AddressBook {
+    /// Loads the address book from the labels in config and the labels file in
+    /// the given directory. The labels file takes precedence.
+    pub fn load(dir: &Path, config: &HashMap<String, LabelConfig>) -> Result<Self> {
+        let book = Self {
+            path: dir.join(LABELS_FILE),
+            ..Default::default()
+        };
+        if let Ok(mut labels) = book.labels.write() {
+            labels.config = config
+                .iter()
+                .map(|(address, label)| (address.to_lowercase(), label.clone().into()))
+                .collect();
+        }
+        if book.path.exists() {
+            let mut reader = csv::Reader::from_path(&book.path)?;
+            for record in reader.deserialize() {
+                let record: Record = record?;
+                let label = (!record.label.is_empty()).then(|| Label {
+                    name: record.label,
+                    color: record.color.filter(|c| !c.is_empty()),
+                });
+                book.set(&record.address, label);
+            }
+        }
+        Ok(book)
+    }
+
+    /// Writes the labels added, edited or removed in-app or imported to the
+    /// labels file, leaving out those from config.
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut writer = csv::Writer::from_path(&self.path)?;
+        let labels = self.labels.read().map_err(|e| eyre!("{e}"))?;
+        let mut addresses: Vec<_> = labels.file.keys().collect();
+        addresses.sort();
+        for address in addresses {
+            let label = labels.file[address].as_ref();
+            writer.serialize(Record {
+                address: address.clone(),
+                label: label.map(|label| label.name.clone()).unwrap_or_default(),
+                color: label.and_then(|label| label.color.clone()),
+            })?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Get the label of an address, if it has one.
+    pub fn get(&self, address: &str) -> Option<Label> {
+        self.labels
+            .read()
+            .ok()
+            .and_then(|labels| labels.get(&address.to_lowercase()).cloned())
+    }
+
+    /// Returns the labelled addresses, sorted.
+    pub fn addresses(&self) -> Vec<String> {
+        let mut addresses: Vec<_> = self
+            .labels
+            .read()
+            .map(|labels| {
+                labels
+                    .config
+                    .keys()
+                    .chain(labels.file.keys())
+                    .filter(|address| labels.get(address).is_some())
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        addresses.sort();
+        addresses.dedup();
+        addresses
+    }
+
+    /// Set or clear the label of an address, to be saved to the labels file.
+    pub fn set(&self, address: &str, label: Option<Label>) {
+        let address = address.to_lowercase();
+        if let Ok(mut labels) = self.labels.write() {
+            // Labels from config are only cleared by recording their removal.
+            if label.is_none() && !labels.config.contains_key(&address) {
+                labels.file.remove(&address);
+            } else {
+                labels.file.insert(address, label);
+            }
+            self.version.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns a number which changes whenever a label is set or cleared.
+    pub fn version(&self) -> u64 {
+        self.version.load(Ordering::Relaxed)
+    }
+
+    /// Imports labels from a CSV file exported from a block explorer.
+    ///
+    /// The address column is the first one whose header contains `address` and
+    /// the label column is the first one whose header contains `label`, `name`
+    /// or `tag`. Returns the number of labels imported.
+    pub fn import(&self, path: &Path) -> Result<usize> {
+        let mut reader = csv::Reader::from_path(path)?;
+        let headers = reader.headers()?.clone();
+        let column = |keys: &[&str]| {
+            headers.iter().position(|h| {
+                let h = h.to_lowercase();
+                keys.iter().any(|k| h.contains(k))
+            })
+        };
+        let address_col =
+            column(&["address"]).ok_or_else(|| eyre!("no address column in {path:?}"))?;
+        let label_col = column(&["label", "name", "tag"])
+            .ok_or_else(|| eyre!("no label column in {path:?}"))?;
+
+        let mut count = 0;
+        for record in reader.records() {
+            let record = record?;
+            let (Some(address), Some(name)) = (record.get(address_col), record.get(label_col))
+            else {
+                continue;
+            };
+            let (address, name) = (address.trim(), name.trim());
+            if !address.starts_with("0x") || name.is_empty() {
+                warn!("Skipping invalid label record: {record:?}");
+                continue;
+            }
+            let color = self.get(address).and_then(|l| l.color);
+            self.set(
+                address,
+                Some(Label {
+                    name: name.to_string(),
+                    color,
+                }),
+            );
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{AddressBook, Label, LabelConfig};
+
+    const ADDR: &str = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("blockrs-{name}-{}", std::process::id()))
+    }
+
+    #[test]
+    fn load_and_save() {
+        let dir = temp_dir("labels");
+        let config = HashMap::from([(ADDR.to_string(), LabelConfig::Name("vitalik".to_string()))]);
+        let book = AddressBook::load(&dir, &config).unwrap();
+        assert_eq!(book.get(&ADDR.to_lowercase()).unwrap().name, "vitalik");
+
+        let label = Label {
+            name: "our-hot-wallet".to_string(),
+            color: Some("yellow".to_string()),
+        };
+        book.set(ADDR, Some(label.clone()));
+        book.save().unwrap();
+
+        // The labels file takes precedence over config.
+        let book = AddressBook::load(&dir, &config).unwrap();
+        assert_eq!(book.get(ADDR).unwrap(), label);
+
+        // Labels from config are not saved to the labels file, so changes to
+        // them in config take effect.
+        let other = "0x00000000219ab540356cBB839Cbe05303d7705Fa";
+        let config = HashMap::from([
+            (ADDR.to_string(), LabelConfig::Name("vitalik".to_string())),
+            (other.to_string(), LabelConfig::Name("deposit".to_string())),
+        ]);
+        let book = AddressBook::load(&dir, &config).unwrap();
+        book.save().unwrap();
+        let config = HashMap::from([(
+            other.to_string(),
+            LabelConfig::Name("beacon deposit".to_string()),
+        )]);
+        let book = AddressBook::load(&dir, &config).unwrap();
+        assert_eq!(book.get(other).unwrap().name, "beacon deposit");
+
+        // Labels from config removed in-app stay removed.
+        book.set(other, None);
+        book.set(ADDR, None);
+        book.save().unwrap();
+        let book = AddressBook::load(&dir, &config).unwrap();
+        assert_eq!(book.get(other), None);
+        assert_eq!(book.get(ADDR), None);
+        assert!(book.addresses().is_empty());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn import() {
+        let dir = temp_dir("import");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("export.csv");
+        std::fs::write(
+            &path,
+            format!(
+                "\"Address\",\"Name Tag\",\"Note\"\n\"{ADDR}\",\"Binance 14\",\"\"\n\"bad\",\"x\",\"\"\n"
+            ),
+        )
+        .unwrap();
+
+        let book = AddressBook::load(&dir, &HashMap::new()).unwrap();
+        assert_eq!(book.import(&path).unwrap(), 1);
+        assert_eq!(book.get(ADDR).unwrap().name, "Binance 14");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
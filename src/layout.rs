@@ -0,0 +1,487 @@
+use std::collections::{HashMap, HashSet};
+
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use serde::{de::Deserializer, Deserialize};
+
+/// Configuration of which panels are shown and how, and which columns their
+/// tables show.
+///
+/// Entries which are missing or invalid fall back to the defaults, with a
+/// warning.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct LayoutConfig {
+    /// Tree of panels, split horizontally or vertically.
+    #[serde(default)]
+    panels: Option<Lenient<Node>>,
+    /// Columns shown by each table, in order, by panel.
+    #[serde(default)]
+    columns: HashMap<String, Lenient<Vec<ColumnConfig>>>,
+}
+
+/// A panel, or a split of the area of the node between its children.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum Node {
+    Panel {
+        panel: String,
+        #[serde(default)]
+        size: Size,
+    },
+    Split {
+        direction: Split,
+        #[serde(default)]
+        size: Size,
+        children: Vec<Node>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Split {
+    Horizontal,
+    Vertical,
+}
+
+/// The size of a panel or column, as a number which is its share of the
+/// space left over, a percentage such as `"30%"`, or a number of cells such
+/// as `"min:5"`, `"max:20"` or `"length:10"`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum Size {
+    Share(u16),
+    Text(String),
+}
+
+impl Default for Size {
+    fn default() -> Self {
+        Self::Share(1)
+    }
+}
+
+impl Size {
+    /// Converts the size to a constraint, if valid.
+    fn constraint(&self) -> Option<Constraint> {
+        let text = match self {
+            Self::Share(share) => return Some(Constraint::Fill(*share)),
+            Self::Text(text) => text.trim(),
+        };
+        if let Some(percentage) = text.strip_suffix('%') {
+            return percentage
+                .parse()
+                .ok()
+                .filter(|percentage| *percentage <= 100)
+                .map(Constraint::Percentage);
+        }
+        let (kind, cells) = text.split_once(':')?;
+        let cells = cells.trim().parse().ok()?;
+        match kind.trim() {
+            "min" => Some(Constraint::Min(cells)),
+            "max" => Some(Constraint::Max(cells)),
+            "length" => Some(Constraint::Length(cells)),
+            _ => None,
+        }
+    }
+}
+
+/// A value, or why it could not be deserialized, so that invalid entries can
+/// fall back to the defaults rather than failing to load the config.
+#[derive(Clone, Debug)]
+struct Lenient<T>(Result<T, String>);
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Lenient<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Buffer the value so that errors in it do not fail the whole config.
+        let value = serde_json::Value::deserialize(deserializer)?;
+        Ok(Self(T::deserialize(value).map_err(|e| e.to_string())))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct ColumnConfig {
+    column: String,
+    width: Option<Size>,
+}
+
+/// A column of a table and its default width.
+#[derive(Clone, Copy, Debug)]
+pub struct Column {
+    pub name: &'static str,
+    pub width: Constraint,
+}
+
+impl Column {
+    pub const fn new(name: &'static str, width: Constraint) -> Self {
+        Self { name, width }
+    }
+}
+
+/// The panels shown and how the area is split between them, as validated
+/// when the config is loaded.
+#[derive(Clone, Debug)]
+pub struct Panels(Node);
+
+impl Default for Panels {
+    fn default() -> Self {
+        Self(default_panels())
+    }
+}
+
+impl Panels {
+    /// Returns the areas of the panels within an area, by name.
+    pub fn areas(&self, area: Rect) -> Vec<(String, Rect)> {
+        let mut areas = Vec::new();
+        split(&self.0, area, &mut areas);
+        areas
+    }
+
+    /// Returns true if the panel is shown.
+    pub fn contains(&self, name: &str) -> bool {
+        fn contains(node: &Node, name: &str) -> bool {
+            match node {
+                Node::Panel { panel, .. } => panel == name,
+                Node::Split { children, .. } => children.iter().any(|child| contains(child, name)),
+            }
+        }
+        contains(&self.0, name)
+    }
+}
+
+impl LayoutConfig {
+    /// Returns the panels shown, along with any warnings about invalid
+    /// entries. Only the given panels can be shown.
+    pub fn panels(&self, known: &[&str]) -> (Panels, Vec<String>) {
+        let mut warnings = Vec::new();
+        let node = match &self.panels {
+            Some(Lenient(Ok(node))) => validate(node, known, &mut HashSet::new(), &mut warnings),
+            Some(Lenient(Err(e))) => {
+                warnings.push(format!("Invalid panels in layout: {e}"));
+                None
+            }
+            None => None,
+        };
+        let panels = match node {
+            Some(node) => Panels(node),
+            None => {
+                if self.panels.is_some() {
+                    warnings.push("No valid panels in layout, using the default".to_string());
+                }
+                Panels::default()
+            }
+        };
+        (panels, warnings)
+    }
+
+    /// Returns warnings about columns configured for tables other than the
+    /// given ones, whose columns cannot be chosen.
+    pub fn unsupported_tables(&self, tables: &[&str]) -> Vec<String> {
+        let mut names = self
+            .columns
+            .keys()
+            .filter(|name| !tables.iter().any(|table| name.eq_ignore_ascii_case(table)))
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+            .into_iter()
+            .map(|name| {
+                format!(
+                    "Columns of {name} cannot be chosen, only those of {}",
+                    tables.join(" and ")
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the indices into the columns of a table of those shown, in
+    /// order, with their widths, along with any warnings about invalid
+    /// entries.
+    pub fn columns(
+        &self,
+        table: &str,
+        columns: &[Column],
+    ) -> (Vec<(usize, Constraint)>, Vec<String>) {
+        let defaults = || {
+            columns
+                .iter()
+                .enumerate()
+                .map(|(i, column)| (i, column.width))
+                .collect()
+        };
+        let mut warnings = Vec::new();
+        // Table names are matched regardless of case.
+        let Some((_, configured)) = self
+            .columns
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(table))
+        else {
+            return (defaults(), warnings);
+        };
+        let configured = match &configured.0 {
+            Ok(configured) => configured,
+            Err(e) => {
+                warnings.push(format!(
+                    "Invalid columns for {table}, using the default: {e}"
+                ));
+                return (defaults(), warnings);
+            }
+        };
+        let mut shown: Vec<(usize, Constraint)> = Vec::new();
+        for config in configured {
+            let Some(i) = columns.iter().position(|c| c.name == config.column) else {
+                warnings.push(format!("Unknown column {} for {table}", config.column));
+                continue;
+            };
+            if shown.iter().any(|(shown, _)| *shown == i) {
+                warnings.push(format!(
+                    "Column {} appears more than once for {table}",
+                    config.column
+                ));
+                continue;
+            }
+            let width = match config.width.as_ref().map(Size::constraint) {
+                Some(Some(width)) => width,
+                Some(None) => {
+                    warnings.push(format!("Invalid width of {table} column {}", config.column));
+                    columns[i].width
+                }
+                None => columns[i].width,
+            };
+            shown.push((i, width));
+        }
+        if shown.is_empty() {
+            warnings.push(format!("No valid columns for {table}, using the default"));
+            return (defaults(), warnings);
+        }
+        (shown, warnings)
+    }
+}
+
+/// Returns the items of a row of a table which are in the columns shown, in
+/// the order shown.
+pub fn pick<T>(items: Vec<T>, columns: &[(usize, Constraint)]) -> Vec<T> {
+    let mut items = items.into_iter().map(Some).collect::<Vec<_>>();
+    columns
+        .iter()
+        .filter_map(|(i, _)| items.get_mut(*i)?.take())
+        .collect()
+}
+
+/// Returns the node with unknown and repeated panels and empty splits left
+/// out, or none if no panels are left.
+fn validate(
+    node: &Node,
+    known: &[&str],
+    seen: &mut HashSet<String>,
+    warnings: &mut Vec<String>,
+) -> Option<Node> {
+    let size = match node {
+        Node::Panel { size, .. } | Node::Split { size, .. } => size,
+    };
+    let size = if size.constraint().is_some() {
+        size.clone()
+    } else {
+        warnings.push(format!("Invalid size {size:?} in layout"));
+        Size::default()
+    };
+    match node {
+        Node::Panel { panel, .. } => {
+            if !known.contains(&panel.as_str()) {
+                warnings.push(format!("Unknown panel {panel} in layout"));
+                return None;
+            }
+            if !seen.insert(panel.clone()) {
+                warnings.push(format!("Panel {panel} appears more than once in layout"));
+                return None;
+            }
+            Some(Node::Panel {
+                panel: panel.clone(),
+                size,
+            })
+        }
+        Node::Split {
+            direction,
+            children,
+            ..
+        } => {
+            let children = children
+                .iter()
+                .filter_map(|child| validate(child, known, seen, warnings))
+                .collect::<Vec<_>>();
+            (!children.is_empty()).then_some(Node::Split {
+                direction: *direction,
+                size,
+                children,
+            })
+        }
+    }
+}
+
+/// Splits an area between the panels of a node.
+fn split(node: &Node, area: Rect, areas: &mut Vec<(String, Rect)>) {
+    match node {
+        Node::Panel { panel, .. } => areas.push((panel.clone(), area)),
+        Node::Split {
+            direction,
+            children,
+            ..
+        } => {
+            let direction = match direction {
+                Split::Horizontal => Direction::Horizontal,
+                Split::Vertical => Direction::Vertical,
+            };
+            let constraints = children.iter().map(|child| match child {
+                Node::Panel { size, .. } | Node::Split { size, .. } => {
+                    size.constraint().unwrap_or(Constraint::Fill(1))
+                }
+            });
+            let rects = Layout::default()
+                .direction(direction)
+                .constraints(constraints)
+                .split(area);
+            for (child, rect) in children.iter().zip(rects.iter()) {
+                split(child, *rect, areas);
+            }
+        }
+    }
+}
+
+/// The default layout: the head, gas and balances panels along the top, over
+/// the transactions list and, beside it, the charts over watched activity.
+fn default_panels() -> Node {
+    let panel = |name: &str, size: &str| Node::Panel {
+        panel: name.to_string(),
+        size: Size::Text(size.to_string()),
+    };
+    let split = |direction, size: &str, children| Node::Split {
+        direction,
+        size: Size::Text(size.to_string()),
+        children,
+    };
+    split(
+        Split::Vertical,
+        "100%",
+        vec![
+            split(
+                Split::Horizontal,
+                "25%",
+                vec![
+                    panel("Head", "45%"),
+                    panel("GasOracle", "20%"),
+                    panel("AccList", "35%"),
+                ],
+            ),
+            split(
+                Split::Horizontal,
+                "75%",
+                vec![
+                    panel("TxList", "70%"),
+                    split(
+                        Split::Vertical,
+                        "30%",
+                        vec![panel("Charts", "50%"), panel("Activity", "50%")],
+                    ),
+                ],
+            ),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui::layout::{Constraint, Rect};
+
+    use super::{pick, Column, LayoutConfig};
+
+    const PANELS: [&str; 6] = [
+        "Head",
+        "AccList",
+        "TxList",
+        "Activity",
+        "Charts",
+        "GasOracle",
+    ];
+
+    fn config(json: &str) -> LayoutConfig {
+        json5::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn panels() {
+        let area = Rect::new(0, 0, 100, 40);
+        let layout = config(
+            r#"{
+                panels: {
+                    direction: "vertical",
+                    children: [
+                        { panel: "Head", size: "length:5" },
+                        { panel: "Unknown" },
+                        { panel: "TxList", size: 2 },
+                        { panel: "TxList" },
+                        { panel: "AccList", size: "lots" },
+                    ],
+                },
+            }"#,
+        );
+        let (panels, warnings) = layout.panels(&PANELS);
+        assert_eq!(
+            panels.areas(area),
+            vec![
+                ("Head".to_string(), Rect::new(0, 0, 100, 5)),
+                ("TxList".to_string(), Rect::new(0, 5, 100, 23)),
+                ("AccList".to_string(), Rect::new(0, 28, 100, 12)),
+            ]
+        );
+        assert_eq!(warnings.len(), 3);
+        assert!(panels.contains("AccList"));
+        assert!(!panels.contains("Activity"));
+
+        // Layouts which cannot be parsed fall back to the default.
+        let layout = config(r#"{ panels: { direction: "diagonal", children: [] } }"#);
+        let (panels, warnings) = layout.panels(&PANELS);
+        assert_eq!(panels.areas(area).len(), 6);
+        assert_eq!(warnings.len(), 2);
+        let (panels, warnings) = config("{}").panels(&PANELS);
+        assert_eq!(panels.areas(area).len(), 6);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn columns() {
+        let columns = [
+            Column::new("hash", Constraint::Fill(3)),
+            Column::new("value", Constraint::Min(5)),
+        ];
+        let layout = config(
+            r#"{
+                columns: {
+                    txlist: [
+                        { column: "value", width: "30%" },
+                        { column: "fee" },
+                        { column: "hash", width: "wide" },
+                        { column: "value" },
+                    ],
+                    AccList: [{ column: "fee" }],
+                    Activity: "hash",
+                },
+            }"#,
+        );
+        let (shown, warnings) = layout.columns("TxList", &columns);
+        assert_eq!(
+            shown,
+            vec![(1, Constraint::Percentage(30)), (0, Constraint::Fill(3))]
+        );
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(pick(vec!["0xabc", "1 ETH"], &shown), vec!["1 ETH", "0xabc"]);
+        let defaults = vec![(0, Constraint::Fill(3)), (1, Constraint::Min(5))];
+        assert_eq!(layout.columns("AccList", &columns).0, defaults);
+
+        // Only the columns of some tables can be chosen.
+        assert_eq!(
+            layout.unsupported_tables(&["TxList", "AccList"]),
+            vec!["Columns of Activity cannot be chosen, only those of TxList and AccList"]
+        );
+    }
+}
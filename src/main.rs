@@ -1,22 +1,35 @@
 use clap::Parser;
 use cli::Cli;
 use color_eyre::Result;
-use config::Config;
+use config::{get_config_dir, Config};
+use labels::AddressBook;
 use providers::eth::EthProvider;
+use tracing::info;
 
 use crate::app::App;
 
 mod action;
+mod alerts;
 mod app;
 mod cli;
 mod components;
 mod config;
 mod errors;
+mod export;
+mod hooks;
+mod keys;
+mod labels;
+mod layout;
 mod logging;
 mod monitor;
+mod names;
 mod providers;
+mod search;
+mod theme;
 mod tui;
 mod types;
+mod units;
+mod watchlist;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -25,6 +38,13 @@ async fn main() -> Result<()> {
 
     let args = Cli::parse();
     let config = Config::new()?;
+    if let Some(path) = &args.import_labels {
+        // Only the labels file is rewritten, so labels from config are left out.
+        let labels = AddressBook::load(&get_config_dir(), &Default::default())?;
+        let count = labels.import(path)?;
+        labels.save()?;
+        info!("Imported {count} labels from {}", path.display());
+    }
     let provider = EthProvider::new(args.rpc_url, &config.app.addresses)?;
     let mut app = App::new(args.tick_rate, args.frame_rate, provider, config)?;
     app.run().await?;
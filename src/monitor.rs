@@ -1,72 +1,655 @@
+use crate::action::Action;
+use crate::alerts::{Alert, AlertEngine, AlertReceiver, AlertRule, AlertSender};
+use crate::hooks::{HookEvent, HookRunner};
+use crate::names::{NameRequest, NameRequestSender};
 use crate::providers::ChainProvider;
 use crate::types::{
-    AccountReceiver, AccountSender, BlockReceiver, BlockSender, TransactionReceiver,
-    TransactionSender,
+    Account, AccountReceiver, AccountSender, Block, BlockReceiver, BlockSender, BlockStats,
+    BlockStatsReceiver, BlockStatsSender, FeesReceiver, FeesSender, Stuck, TransactionReceiver,
+    TransactionSender, WithdrawalReceiver, WithdrawalSender,
 };
-use tokio::sync::mpsc::unbounded_channel;
+use crate::watchlist::Watchlist;
+use color_eyre::eyre::eyre;
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+use tokio::{
+    sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+    time::Instant,
+};
+use tracing::warn;
+
+/// How long after failing to resolve names in the watch list they are tried
+/// again, unless they are due to be refreshed sooner.
+const WATCHLIST_RETRY: Duration = Duration::from_secs(30);
+
+/// Receivers of the chain data retrieved by a [`ChainMonitor`].
+pub struct Receivers {
+    pub blocks: BlockReceiver,
+    pub transactions: TransactionReceiver,
+    pub accounts: AccountReceiver,
+    /// Receives the same transactions as `transactions`, for components that
+    /// accumulate transactions across blocks.
+    pub activity: TransactionReceiver,
+    pub alerts: AlertReceiver,
+    /// Receives transactions newly seen pending in the transaction pool.
+    pub pending: TransactionReceiver,
+    /// Receives the same transactions as `transactions`, for matching pending
+    /// transactions to the blocks they are included in.
+    pub included: TransactionReceiver,
+    /// Receive the same blocks and accounts as `blocks` and `accounts`, for
+    /// charting metrics over time.
+    pub chart_blocks: BlockReceiver,
+    pub chart_accounts: AccountReceiver,
+    pub fees: FeesReceiver,
+    pub stats: BlockStatsReceiver,
+    pub withdrawals: WithdrawalReceiver,
+}
+
+/// Changes to the chain data retrieved by a [`ChainMonitor`], requested from
+/// the UI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MonitorCommand {
+    /// Watch the account at an address or name.
+    Watch(String),
+    /// Stop watching the account at an address or name.
+    Unwatch(String),
+    /// Retrieve chain data from the endpoint at a URL.
+    Endpoint(String),
+}
+
+pub type MonitorCommandSender = UnboundedSender<MonitorCommand>;
+pub type MonitorCommandReceiver = UnboundedReceiver<MonitorCommand>;
 
 /// Runtime responsible for managing retrieval of latest chain data.
 pub struct ChainMonitor<P> {
     block_tx: BlockSender,
     transaction_tx: TransactionSender,
     account_tx: AccountSender,
-    block_rx: Option<BlockReceiver>,
-    transaction_rx: Option<TransactionReceiver>,
-    account_rx: Option<AccountReceiver>,
+    activity_tx: TransactionSender,
+    alert_tx: AlertSender,
+    pending_tx: TransactionSender,
+    included_tx: TransactionSender,
+    chart_block_tx: BlockSender,
+    chart_account_tx: AccountSender,
+    fees_tx: FeesSender,
+    stats_tx: BlockStatsSender,
+    withdrawal_tx: WithdrawalSender,
+    receivers: Option<Receivers>,
+    command_rx: Option<MonitorCommandReceiver>,
+    /// Reports the outcome of each command applied.
+    action_tx: Option<UnboundedSender<Action>>,
+    name_tx: Option<NameRequestSender>,
+    watchlist: Watchlist,
+    alerts: AlertEngine,
+    hooks: Option<HookRunner>,
+    /// Balances of accounts at the last block, by address.
+    balances: HashMap<String, Account>,
+    /// Whether to poll for pending transactions.
+    mempool: bool,
+    /// Whether the endpoint turned out not to expose its transaction pool, in
+    /// which case it is not polled until the endpoint changes.
+    mempool_unsupported: bool,
+    /// Hashes of the transactions pending at the last poll.
+    pending: HashSet<String>,
+    nonce_gaps: NonceGaps,
+    /// Number of blocks a nonce gap can last for before the pending
+    /// transactions of an account are flagged as stuck.
+    stuck_after: u64,
     provider: P,
     head_number: u64,
+    watchlist_refresh_rate: Duration,
+    watchlist_resolved_at: Option<Instant>,
+    /// Whether any names failed to resolve when last resolved.
+    watchlist_failed: bool,
 }
 
 impl<P> ChainMonitor<P> {
     /// Constructs a new `ChainMonitor` based on a specific `ChainProvider`.
-    pub fn new(provider: P) -> Self {
+    pub fn new(provider: P, watchlist: Watchlist) -> Self {
         let (block_tx, block_rx) = unbounded_channel();
         let (transaction_tx, transaction_rx) = unbounded_channel();
         let (account_tx, account_rx) = unbounded_channel();
+        let (activity_tx, activity_rx) = unbounded_channel();
+        let (alert_tx, alert_rx) = unbounded_channel();
+        let (pending_tx, pending_rx) = unbounded_channel();
+        let (included_tx, included_rx) = unbounded_channel();
+        let (chart_block_tx, chart_block_rx) = unbounded_channel();
+        let (chart_account_tx, chart_account_rx) = unbounded_channel();
+        let (fees_tx, fees_rx) = unbounded_channel();
+        let (stats_tx, stats_rx) = unbounded_channel();
+        let (withdrawal_tx, withdrawal_rx) = unbounded_channel();
         Self {
             provider,
             block_tx,
             transaction_tx,
             account_tx,
-            block_rx: block_rx.into(),
-            transaction_rx: transaction_rx.into(),
-            account_rx: account_rx.into(),
+            activity_tx,
+            alert_tx,
+            pending_tx,
+            included_tx,
+            chart_block_tx,
+            chart_account_tx,
+            fees_tx,
+            stats_tx,
+            withdrawal_tx,
+            receivers: Some(Receivers {
+                blocks: block_rx,
+                transactions: transaction_rx,
+                accounts: account_rx,
+                activity: activity_rx,
+                alerts: alert_rx,
+                pending: pending_rx,
+                included: included_rx,
+                chart_blocks: chart_block_rx,
+                chart_accounts: chart_account_rx,
+                fees: fees_rx,
+                stats: stats_rx,
+                withdrawals: withdrawal_rx,
+            }),
+            command_rx: None,
+            name_tx: None,
+            action_tx: None,
+            watchlist,
+            alerts: AlertEngine::new(Vec::new()),
+            hooks: None,
+            balances: HashMap::new(),
+            mempool: false,
+            mempool_unsupported: false,
+            pending: HashSet::new(),
+            nonce_gaps: NonceGaps::default(),
+            stuck_after: u64::MAX,
             head_number: 0u64,
+            watchlist_refresh_rate: Duration::MAX,
+            watchlist_resolved_at: None,
+            watchlist_failed: false,
         }
     }
 
+    /// Requests names for the addresses of new transactions and accounts
+    /// through the given sender.
+    pub fn name_requests(mut self, name_tx: NameRequestSender) -> Self {
+        self.name_tx = Some(name_tx);
+        self
+    }
+
+    /// Applies commands received through the given receiver before each
+    /// retrieval of chain data, reporting the outcome of each as a status or
+    /// error through the given sender.
+    pub fn commands(
+        mut self,
+        command_rx: MonitorCommandReceiver,
+        action_tx: UnboundedSender<Action>,
+    ) -> Self {
+        self.command_rx = Some(command_rx);
+        self.action_tx = Some(action_tx);
+        self
+    }
+
+    /// Sets how often names in the watch list are resolved.
+    pub fn watchlist_refresh_rate(mut self, refresh_rate: Duration) -> Self {
+        self.watchlist_refresh_rate = refresh_rate;
+        self
+    }
+
+    /// Sets the rules evaluated over incoming chain data.
+    pub fn alert_rules(mut self, rules: Vec<AlertRule>) -> Self {
+        self.alerts = AlertEngine::new(rules);
+        self
+    }
+
+    /// Runs hooks for new blocks, balance changes and alerts.
+    pub fn hooks(mut self, hooks: HookRunner) -> Self {
+        self.hooks = Some(hooks);
+        self
+    }
+
+    /// Polls for pending transactions along with new blocks.
+    pub fn mempool(mut self, enabled: bool) -> Self {
+        self.mempool = enabled;
+        self
+    }
+
+    /// Flags the pending transactions of watched accounts as stuck when none
+    /// have been included for more than the given number of blocks.
+    pub fn stuck_after(mut self, blocks: u64) -> Self {
+        self.stuck_after = blocks;
+        self
+    }
+
     /// Relinquishes ownership of receivers for various chain data receivers
     /// required for the delivery of chain data to the UI of the app.
     ///
     /// # Panics
     ///
     /// If this function is called more than once, it will panic.
-    pub fn receivers(&mut self) -> (BlockReceiver, TransactionReceiver, AccountReceiver) {
-        (
-            self.block_rx.take().unwrap(),
-            self.transaction_rx.take().unwrap(),
-            self.account_rx.take().unwrap(),
-        )
+    pub fn receivers(&mut self) -> Receivers {
+        self.receivers.take().unwrap()
     }
 }
 
 impl<P: ChainProvider + Sync> ChainMonitor<P> {
     /// Uses a [`ChainProvider`] to get the latest block, transactions, and account balances.
     pub async fn run(&mut self) -> color_eyre::Result<()> {
+        self.apply_commands().await?;
+
+        // Resolve names in the watch list on startup and periodically thereafter,
+        // trying again sooner if any fail. Names which fail to resolve keep
+        // their last address, so balances are retrieved regardless.
+        let wait = if self.watchlist_failed {
+            WATCHLIST_RETRY.min(self.watchlist_refresh_rate)
+        } else {
+            self.watchlist_refresh_rate
+        };
+        if self
+            .watchlist_resolved_at
+            .is_none_or(|at| at.elapsed() >= wait)
+        {
+            let failures = self.provider.resolve_watchlist().await;
+            self.watchlist_resolved_at = Some(Instant::now());
+            self.watchlist_failed = !failures.is_empty();
+            for (name, e) in failures {
+                warn!("Failed to resolve {name}: {e}");
+                if let Some(action_tx) = &self.action_tx {
+                    action_tx.send(Action::Error(format!("Failed to resolve {name}: {e}")))?;
+                }
+            }
+        }
+
+        // Evaluate alerts that do not depend on new chain data.
+        let alerts = self.alerts.on_tick();
+        self.send_alerts(alerts)?;
+
         // Retrieve the latest block.
         let block = self.provider.head().await?;
         // Do not send duplicate blocks.
         if block.number > self.head_number {
             self.head_number = block.number;
-            // Send the block.
-            self.block_tx.send(vec![block])?;
-            // Get and send the transactions.
+            // Get the transactions and withdrawals.
             let txs = self.provider.transactions().await?;
+            let withdrawals = self.provider.withdrawals().await?;
+            // Get the account balances and keep track of the accounts being watched.
+            let mut bals = self.provider.balances().await?;
+            self.detect_stuck(&block, &mut bals).await;
+            self.watchlist
+                .replace(bals.iter().map(|acc| acc.address.clone()));
+            // Request names for all the addresses about to be displayed.
+            if let Some(name_tx) = &self.name_tx {
+                // Watched accounts come first, in case not all are looked up.
+                let addresses = bals
+                    .iter()
+                    .map(|acc| acc.address.clone())
+                    .chain(txs.iter().flat_map(|tx| [tx.from.clone(), tx.to.clone()]))
+                    .filter(|addr| !addr.is_empty())
+                    .collect();
+                name_tx.send(NameRequest::Lookup(addresses))?;
+            }
+            // Run hooks for the new block and any changed balances.
+            if let Some(hooks) = &self.hooks {
+                hooks.dispatch(HookEvent::new_block(&block, txs.len()));
+                for acc in &bals {
+                    match self.balances.get(&acc.address) {
+                        Some(prev) if prev.balance != acc.balance => {
+                            hooks.dispatch(HookEvent::balance_changed(block.number, prev, acc));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            self.balances = bals
+                .iter()
+                .map(|acc| (acc.address.clone(), acc.clone()))
+                .collect();
+            // Evaluate alerts over the new data.
+            let alerts = self.alerts.on_block(&block, &txs, &bals);
+            self.send_alerts(alerts)?;
+            // Send the block, transactions and account balances.
+            self.stats_tx
+                .send(vec![BlockStats::new(block.number, &txs)])?;
+            self.chart_block_tx.send(vec![block.clone()])?;
+            self.block_tx.send(vec![block])?;
+            if self.mempool {
+                self.included_tx.send(txs.clone())?;
+            }
+            self.activity_tx.send(txs.clone())?;
+            self.withdrawal_tx.send(withdrawals)?;
             self.transaction_tx.send(txs)?;
-            // Get and send the account balances.
-            let bals = self.provider.balances().await?;
+            self.chart_account_tx.send(bals.clone())?;
             self.account_tx.send(bals)?;
+            // Estimate fees for the next block. Not all nodes support this, so
+            // failures do not hold up the rest of the chain data.
+            match self.provider.fees().await {
+                Ok(Some(fees)) => self.fees_tx.send(fees)?,
+                Ok(None) => {}
+                Err(e) => warn!("Failed to estimate fees: {e}"),
+            }
+        }
+
+        // Send transactions which have entered the transaction pool since the last poll.
+        if self.mempool && !self.mempool_unsupported {
+            match self.provider.pending_transactions().await {
+                Ok(Some(pending)) => {
+                    let new: Vec<_> = pending
+                        .iter()
+                        .filter(|tx| !self.pending.contains(&tx.hash))
+                        .cloned()
+                        .collect();
+                    self.pending = pending.into_iter().map(|tx| tx.hash).collect();
+                    if !new.is_empty() {
+                        self.pending_tx.send(new)?;
+                    }
+                }
+                // Stop polling, rather than failing on every tick, if the
+                // endpoint does not expose its transaction pool.
+                Ok(None) => {
+                    self.mempool_unsupported = true;
+                    self.pending.clear();
+                    return Err(eyre!(
+                        "The endpoint does not support txpool_content, so the mempool is no longer polled"
+                    ));
+                }
+                Err(e) => warn!("Failed to retrieve pending transactions: {e}"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the commands received since the last retrieval.
+    async fn apply_commands(&mut self) -> color_eyre::Result<()> {
+        let Some(command_rx) = self.command_rx.as_mut() else {
+            return Ok(());
+        };
+        let mut commands = Vec::new();
+        while let Ok(command) = command_rx.try_recv() {
+            commands.push(command);
+        }
+        // A command which fails is reported without holding up the others.
+        for command in commands {
+            let result = self.apply_command(&command).await;
+            if let (Ok(()), MonitorCommand::Endpoint(url)) = (&result, &command) {
+                // Names are looked up through the new endpoint too, and the
+                // blocks of the old one are not followed on from.
+                if let Some(name_tx) = &self.name_tx {
+                    name_tx.send(NameRequest::Endpoint(url.clone()))?;
+                }
+                if let Some(action_tx) = &self.action_tx {
+                    action_tx.send(Action::ClearHistory)?;
+                }
+            }
+            let action = match result {
+                Ok(()) => Action::Status(match &command {
+                    MonitorCommand::Watch(account) => {
+                        format!("Watching {account} from the next block")
+                    }
+                    MonitorCommand::Unwatch(account) => {
+                        format!("Stopped watching {account} from the next block")
+                    }
+                    MonitorCommand::Endpoint(url) => format!("Switched endpoint to {url}"),
+                }),
+                Err(e) => Action::Error(match &command {
+                    MonitorCommand::Watch(account) => format!("Failed to watch {account}: {e}"),
+                    MonitorCommand::Unwatch(account) => {
+                        format!("Failed to stop watching {account}: {e}")
+                    }
+                    MonitorCommand::Endpoint(url) => {
+                        format!("Failed to switch endpoint to {url}: {e}")
+                    }
+                }),
+            };
+            if let Some(action_tx) = &self.action_tx {
+                action_tx.send(action)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies a single command to the provider.
+    async fn apply_command(&mut self, command: &MonitorCommand) -> Result<(), P::Error> {
+        match command {
+            MonitorCommand::Watch(account) => self.provider.watch(account).await,
+            MonitorCommand::Unwatch(account) => self.provider.unwatch(account).await,
+            MonitorCommand::Endpoint(url) => {
+                self.provider.set_endpoint(url).await?;
+                // The new endpoint may be behind, serve another chain, or
+                // expose its transaction pool where the old one did not.
+                self.mempool_unsupported = false;
+                self.head_number = 0;
+                self.watchlist_resolved_at = None;
+                Ok(())
+            }
+        }
+    }
+
+    /// Flags accounts whose nonce gap has lasted for too many blocks, along
+    /// with the pending transaction holding up the others.
+    async fn detect_stuck(&mut self, block: &Block, accounts: &mut [Account]) {
+        let ages = self.nonce_gaps.update(block.number, accounts);
+        for (acc, age) in accounts.iter_mut().zip(ages) {
+            let Some(blocks) = age.filter(|blocks| *blocks > self.stuck_after) else {
+                continue;
+            };
+            // The transaction with the next nonce is the one blocking the others.
+            let transaction = match self
+                .provider
+                .pending_transaction(&acc.address, acc.nonce)
+                .await
+            {
+                Ok(tx) => tx,
+                Err(e) => {
+                    warn!("Failed to get pending transaction of {}: {e}", acc.address);
+                    None
+                }
+            };
+            acc.stuck = Some(Stuck {
+                blocks,
+                transaction,
+                base_fee: block.base_fee,
+            });
+        }
+    }
+
+    /// Sends alerts to the UI and runs hooks for them.
+    fn send_alerts(&self, alerts: Vec<Alert>) -> color_eyre::Result<()> {
+        if alerts.is_empty() {
+            return Ok(());
+        }
+        if let Some(hooks) = &self.hooks {
+            for alert in &alerts {
+                hooks.dispatch(alert.into());
+            }
         }
+        self.alert_tx.send(alerts)?;
         Ok(())
     }
 }
+
+/// Tracks how long watched accounts have had pending transactions without
+/// any of them being included.
+#[derive(Debug, Default)]
+struct NonceGaps {
+    /// The included nonce of each account with a gap and the block at which
+    /// the gap was first seen, by address.
+    gaps: HashMap<String, (u64, u64)>,
+}
+
+impl NonceGaps {
+    /// Updates the gaps with the nonces of accounts at a new block. Returns
+    /// the number of blocks the gap of each account has lasted for, if any.
+    fn update(&mut self, number: u64, accounts: &[Account]) -> Vec<Option<u64>> {
+        let mut gaps = HashMap::new();
+        let ages = accounts
+            .iter()
+            .map(|acc| {
+                if acc.pending_nonce <= acc.nonce {
+                    return None;
+                }
+                // The gap restarts whenever a transaction is included.
+                let since = match self.gaps.get(&acc.address) {
+                    Some((nonce, since)) if *nonce == acc.nonce => *since,
+                    _ => number,
+                };
+                gaps.insert(acc.address.clone(), (acc.nonce, since));
+                Some(number.saturating_sub(since))
+            })
+            .collect();
+        self.gaps = gaps;
+        ages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+    use tokio::{sync::mpsc::unbounded_channel, time::Instant};
+
+    use super::{ChainMonitor, MonitorCommand, NonceGaps, WATCHLIST_RETRY};
+    use crate::{
+        action::Action,
+        names::NameRequest,
+        providers::ChainProvider,
+        types::{Account, Block, Transaction},
+        watchlist::Watchlist,
+    };
+
+    /// A provider which can watch and resolve any account but `bad.eth`.
+    struct Provider;
+
+    #[async_trait::async_trait]
+    impl ChainProvider for Provider {
+        type Error = std::io::Error;
+
+        async fn head(&mut self) -> Result<Block, Self::Error> {
+            Ok(Block::default())
+        }
+
+        async fn transactions(&self) -> Result<Vec<Transaction>, Self::Error> {
+            Ok(Vec::new())
+        }
+
+        async fn balances(&self) -> Result<Vec<Account>, Self::Error> {
+            Ok(Vec::new())
+        }
+
+        async fn resolve_watchlist(&mut self) -> Vec<(String, Self::Error)> {
+            vec![(
+                "bad.eth".to_string(),
+                std::io::Error::other("name not found"),
+            )]
+        }
+
+        async fn watch(&mut self, account: &str) -> Result<(), Self::Error> {
+            match account {
+                "bad.eth" => Err(std::io::Error::other("name not found")),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolve_watchlist() {
+        let (_command_tx, command_rx) = unbounded_channel();
+        let (action_tx, mut action_rx) = unbounded_channel();
+        let mut monitor = ChainMonitor::new(Provider, Watchlist::default())
+            .watchlist_refresh_rate(Duration::from_secs(3600))
+            .commands(command_rx, action_tx);
+
+        // Failures are reported without holding up the rest of the tick.
+        monitor.run().await.unwrap();
+        assert_eq!(
+            action_rx.try_recv().unwrap(),
+            Action::Error("Failed to resolve bad.eth: name not found".to_string())
+        );
+        monitor.run().await.unwrap();
+        assert!(action_rx.try_recv().is_err());
+
+        // Failures are retried well before the next refresh.
+        monitor.watchlist_resolved_at = Instant::now().checked_sub(WATCHLIST_RETRY);
+        monitor.run().await.unwrap();
+        assert!(action_rx.try_recv().is_ok());
+    }
+
+    #[tokio::test]
+    async fn mempool_unsupported() {
+        let (command_tx, command_rx) = unbounded_channel();
+        let (action_tx, _action_rx) = unbounded_channel();
+        let mut monitor = ChainMonitor::new(Provider, Watchlist::default())
+            .mempool(true)
+            .commands(command_rx, action_tx);
+
+        // Reported once, then no longer polled.
+        assert!(monitor.run().await.is_err());
+        assert!(monitor.run().await.is_ok());
+
+        // Polled again from another endpoint.
+        command_tx
+            .send(MonitorCommand::Endpoint(
+                "http://localhost:8545".to_string(),
+            ))
+            .unwrap();
+        assert!(monitor.run().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn commands() {
+        let (command_tx, command_rx) = unbounded_channel();
+        let (action_tx, mut action_rx) = unbounded_channel();
+        let (name_tx, mut name_rx) = unbounded_channel();
+        let mut monitor = ChainMonitor::new(Provider, Watchlist::default())
+            .commands(command_rx, action_tx)
+            .name_requests(name_tx);
+        command_tx
+            .send(MonitorCommand::Watch("bad.eth".to_string()))
+            .unwrap();
+        command_tx
+            .send(MonitorCommand::Watch("good.eth".to_string()))
+            .unwrap();
+        monitor.apply_commands().await.unwrap();
+
+        // The failure is reported without holding up the next command.
+        assert_eq!(
+            action_rx.try_recv().unwrap(),
+            Action::Error("Failed to watch bad.eth: name not found".to_string())
+        );
+        assert_eq!(
+            action_rx.try_recv().unwrap(),
+            Action::Status("Watching good.eth from the next block".to_string())
+        );
+        assert!(action_rx.try_recv().is_err());
+
+        // Switching endpoint switches name lookups and clears the history.
+        let url = "http://localhost:8545".to_string();
+        command_tx
+            .send(MonitorCommand::Endpoint(url.clone()))
+            .unwrap();
+        monitor.apply_commands().await.unwrap();
+        assert_eq!(name_rx.try_recv().unwrap(), NameRequest::Endpoint(url));
+        assert_eq!(action_rx.try_recv().unwrap(), Action::ClearHistory);
+        assert!(matches!(action_rx.try_recv().unwrap(), Action::Status(_)));
+    }
+
+    #[test]
+    fn nonce_gaps() {
+        let account = |address: &str, nonce, pending_nonce| Account {
+            address: address.to_string(),
+            nonce,
+            pending_nonce,
+            ..Default::default()
+        };
+        let mut gaps = NonceGaps::default();
+        let ages = gaps.update(10, &[account("0xa", 5, 7), account("0xb", 1, 1)]);
+        assert_eq!(ages, vec![Some(0), None]);
+        let ages = gaps.update(12, &[account("0xa", 5, 7), account("0xb", 1, 2)]);
+        assert_eq!(ages, vec![Some(2), Some(0)]);
+        // Inclusion of a transaction restarts the gap.
+        let ages = gaps.update(13, &[account("0xa", 6, 7), account("0xb", 1, 2)]);
+        assert_eq!(ages, vec![Some(0), Some(1)]);
+        // Closing the gap forgets it.
+        let ages = gaps.update(14, &[account("0xa", 7, 7), account("0xb", 1, 2)]);
+        assert_eq!(ages, vec![None, Some(2)]);
+        let ages = gaps.update(15, &[account("0xa", 7, 8)]);
+        assert_eq!(ages, vec![Some(0)]);
+    }
+}
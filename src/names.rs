@@ -0,0 +1,372 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use color_eyre::Result;
+use futures::{stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tracing::{debug, error, warn};
+
+use crate::{providers::ChainProvider, types::Abridged};
+
+/// A request to the [`NameService`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NameRequest {
+    /// Look up the names of addresses.
+    Lookup(Vec<String>),
+    /// Look up names through the endpoint at a URL from now on.
+    Endpoint(String),
+}
+
+pub type NameRequestSender = UnboundedSender<NameRequest>;
+pub type NameRequestReceiver = UnboundedReceiver<NameRequest>;
+
+/// Name of the file in the data directory used to cache name lookups.
+const CACHE_FILE: &str = "names.json";
+
+/// Maximum number of addresses looked up for a single request, so that the
+/// lookups for a busy block finish before the next block arrives.
+const MAX_LOOKUPS: usize = 64;
+
+/// Number of lookups performed at once.
+const LOOKUP_CONCURRENCY: usize = 4;
+
+/// Human-readable names of addresses, shared between the name service and the
+/// UI, with a version bumped whenever a name changes.
+#[derive(Clone, Debug, Default)]
+pub struct Names(Arc<RwLock<HashMap<String, String>>>, Arc<AtomicU64>);
+
+impl Names {
+    /// Get the name of an address, if it has one.
+    pub fn get(&self, address: &str) -> Option<String> {
+        self.0
+            .read()
+            .ok()
+            .and_then(|names| names.get(&address.to_lowercase()).cloned())
+    }
+
+    /// Set or clear the name of an address.
+    pub fn set(&self, address: &str, name: Option<String>) {
+        let address = address.to_lowercase();
+        if let Ok(mut names) = self.0.write() {
+            if names.get(&address) == name.as_ref() {
+                return;
+            }
+            match name {
+                Some(name) => names.insert(address, name),
+                None => names.remove(&address),
+            };
+            self.1.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns a number which changes whenever a name is set or cleared.
+    pub fn version(&self) -> u64 {
+        self.1.load(Ordering::Relaxed)
+    }
+
+    /// Returns the name of the address if it has one, or the abridged address otherwise.
+    pub fn display(&self, address: &str) -> String {
+        self.get(address).unwrap_or_else(|| address.abridged())
+    }
+}
+
+/// The result of a single name lookup.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    name: Option<String>,
+    /// Seconds since the unix epoch at which the lookup was performed.
+    resolved_at: u64,
+}
+
+/// Cache of name lookups that is persisted to disk.
+///
+/// Negative results are cached too so that addresses without names are not
+/// looked up every time they are displayed.
+#[derive(Debug, Default)]
+pub struct NameCache {
+    path: PathBuf,
+    ttl: Duration,
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl NameCache {
+    /// Loads the cache from the given directory. Starts with an empty cache if
+    /// there is no cache file or it cannot be read.
+    pub fn load(dir: &Path, ttl: Duration) -> Self {
+        let path = dir.join(CACHE_FILE);
+        let entries = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| {
+                serde_json::from_str(&content)
+                    .inspect_err(|e| warn!("Discarding invalid name cache: {e}"))
+                    .ok()
+            })
+            .unwrap_or_default();
+        Self { path, ttl, entries }
+    }
+
+    /// Writes the cache to disk.
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&self.path, serde_json::to_string(&self.entries)?)?;
+        Ok(())
+    }
+
+    /// Returns the cached lookup result for an address, unless it has expired.
+    ///
+    /// The outer `Option` indicates whether there is a valid entry, the inner
+    /// one whether the address has a name.
+    pub fn get(&self, address: &str) -> Option<Option<String>> {
+        let entry = self.entries.get(&address.to_lowercase())?;
+        (now().saturating_sub(entry.resolved_at) < self.ttl.as_secs()).then(|| entry.name.clone())
+    }
+
+    /// Records the result of a lookup.
+    pub fn insert(&mut self, address: &str, name: Option<String>) {
+        let entry = CacheEntry {
+            name,
+            resolved_at: now(),
+        };
+        self.entries.insert(address.to_lowercase(), entry);
+    }
+}
+
+/// Seconds since the unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Runtime responsible for looking up the names of addresses on request.
+///
+/// Lookups can take several requests each, so they are performed separately
+/// from the retrieval of chain data in order not to delay it.
+pub struct NameService<P> {
+    provider: P,
+    cache: NameCache,
+    names: Names,
+    requests_rx: NameRequestReceiver,
+}
+
+impl<P> NameService<P> {
+    /// Constructs a new `NameService` which publishes names to `names`.
+    pub fn new(
+        provider: P,
+        cache: NameCache,
+        names: Names,
+        requests_rx: NameRequestReceiver,
+    ) -> Self {
+        Self {
+            provider,
+            cache,
+            names,
+            requests_rx,
+        }
+    }
+}
+
+impl<P: ChainProvider> NameService<P> {
+    /// Looks up names for requested addresses until all senders are dropped.
+    ///
+    /// Requests which arrive while names are being looked up are coalesced:
+    /// endpoint switches are applied first, and only the addresses of the
+    /// latest request are looked up, as those of earlier ones are stale.
+    pub async fn run(mut self) {
+        while let Some(request) = self.requests_rx.recv().await {
+            let mut requests = vec![request];
+            while let Ok(request) = self.requests_rx.try_recv() {
+                requests.push(request);
+            }
+            let mut latest = None;
+            for request in requests {
+                match request {
+                    NameRequest::Lookup(addresses) => latest = Some(addresses),
+                    NameRequest::Endpoint(url) => {
+                        if let Err(e) = self.provider.set_endpoint(&url).await {
+                            error!("Failed to switch name lookups to {url}: {e}");
+                        }
+                    }
+                }
+            }
+            if let Some(addresses) = latest {
+                self.lookup(addresses).await;
+            }
+        }
+    }
+
+    /// Publishes the names of the addresses, looking up those which are not
+    /// cached a few at a time and up to [`MAX_LOOKUPS`] of them.
+    async fn lookup(&mut self, addresses: Vec<String>) {
+        let mut seen = HashSet::new();
+        let mut uncached = Vec::new();
+        for address in addresses {
+            if !seen.insert(address.to_lowercase()) {
+                continue;
+            }
+            match self.cache.get(&address) {
+                Some(name) => self.names.set(&address, name),
+                None => uncached.push(address),
+            }
+        }
+        if uncached.len() > MAX_LOOKUPS {
+            debug!(
+                "Looking up {MAX_LOOKUPS} of {} uncached names",
+                uncached.len()
+            );
+            uncached.truncate(MAX_LOOKUPS);
+        }
+
+        let provider = &self.provider;
+        let results: Vec<_> = stream::iter(uncached)
+            .map(|address| async move {
+                let name = provider.lookup_name(&address).await;
+                (address, name)
+            })
+            .buffer_unordered(LOOKUP_CONCURRENCY)
+            .collect()
+            .await;
+        let mut dirty = false;
+        for (address, name) in results {
+            match name {
+                Ok(name) => {
+                    self.cache.insert(&address, name.clone());
+                    self.names.set(&address, name);
+                    dirty = true;
+                }
+                Err(e) => warn!("Failed to look up name of {address}: {e}"),
+            }
+        }
+        if dirty {
+            if let Err(e) = self.cache.save() {
+                error!("Failed to save name cache: {e}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::{NameCache, NameRequest, NameService, Names, MAX_LOOKUPS};
+    use crate::{
+        providers::ChainProvider,
+        types::{Account, Block, Transaction},
+    };
+
+    /// A provider which records the lookups and endpoint switches made
+    /// through it, naming every address `name.eth`.
+    #[derive(Clone, Default)]
+    struct Provider(Arc<Mutex<Vec<String>>>);
+
+    #[async_trait::async_trait]
+    impl ChainProvider for Provider {
+        type Error = std::io::Error;
+
+        async fn head(&mut self) -> Result<Block, Self::Error> {
+            Ok(Block::default())
+        }
+
+        async fn transactions(&self) -> Result<Vec<Transaction>, Self::Error> {
+            Ok(Vec::new())
+        }
+
+        async fn balances(&self) -> Result<Vec<Account>, Self::Error> {
+            Ok(Vec::new())
+        }
+
+        async fn set_endpoint(&mut self, url: &str) -> Result<(), Self::Error> {
+            self.0.lock().unwrap().push(url.to_string());
+            Ok(())
+        }
+
+        async fn lookup_name(&self, address: &str) -> Result<Option<String>, Self::Error> {
+            self.0.lock().unwrap().push(address.to_string());
+            Ok(Some("name.eth".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn coalesces_requests() {
+        let dir = std::env::temp_dir().join(format!("blockrs-lookups-{}", std::process::id()));
+        let provider = Provider::default();
+        let names = Names::default();
+        let (tx, rx) = unbounded_channel();
+        let cache = NameCache::load(&dir, Duration::from_secs(60));
+        let service = NameService::new(provider.clone(), cache, names.clone(), rx);
+
+        // Requests queued behind each other are coalesced into the latest.
+        tx.send(NameRequest::Lookup(vec!["0xstale".to_string()]))
+            .unwrap();
+        tx.send(NameRequest::Endpoint("http://localhost:8545".to_string()))
+            .unwrap();
+        let addresses: Vec<_> = (0..MAX_LOOKUPS + 10)
+            .map(|i| format!("0x{i:x}"))
+            .chain(["0x1".to_string()])
+            .collect();
+        tx.send(NameRequest::Lookup(addresses)).unwrap();
+        drop(tx);
+        service.run().await;
+
+        let calls = provider.0.lock().unwrap().clone();
+        assert_eq!(calls[0], "http://localhost:8545");
+        // Stale, repeated and excess addresses are not looked up.
+        assert_eq!(calls.len(), 1 + MAX_LOOKUPS);
+        assert!(!calls.contains(&"0xstale".to_string()));
+        assert_eq!(names.get("0x1").unwrap(), "name.eth");
+        assert!(names.get("0xstale").is_none());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn names() {
+        let names = Names::default();
+        let addr = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+        assert_eq!(names.display(addr), "0xd8dA..6045");
+        names.set(addr, Some("vitalik.eth".to_string()));
+        assert_eq!(names.get(&addr.to_lowercase()).unwrap(), "vitalik.eth");
+        assert_eq!(names.display(addr), "vitalik.eth");
+        names.set(addr, None);
+        assert!(names.get(addr).is_none());
+    }
+
+    #[test]
+    fn cache() {
+        let dir = std::env::temp_dir().join(format!("blockrs-names-{}", std::process::id()));
+        let mut cache = NameCache::load(&dir, Duration::from_secs(60));
+        assert!(cache.get("0xabc").is_none());
+        cache.insert("0xABC", Some("abc.eth".to_string()));
+        cache.insert("0xdef", None);
+        assert_eq!(cache.get("0xabc"), Some(Some("abc.eth".to_string())));
+        assert_eq!(cache.get("0xdef"), Some(None));
+        cache.save().unwrap();
+
+        // Entries survive a reload.
+        let cache = NameCache::load(&dir, Duration::from_secs(60));
+        assert_eq!(cache.get("0xabc"), Some(Some("abc.eth".to_string())));
+
+        // Entries expire.
+        let cache = NameCache::load(&dir, Duration::ZERO);
+        assert!(cache.get("0xabc").is_none());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
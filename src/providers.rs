@@ -1,13 +1,13 @@
 pub mod eth;
 
-use crate::types::{Account, Block, Transaction};
+use crate::types::{Account, Block, Fees, Transaction, Withdrawal};
 
 /// Represents a service that provides chain data in the form of blocks, transactions, and accounts.
 ///
 /// The data provided is designed to be chain agnostic. It is used to render
 /// information to the UI of the app.
 #[async_trait::async_trait]
-pub trait ChainProvider {
+pub trait ChainProvider: Send + Sync {
     type Error: std::error::Error + Send + Sync + 'static;
 
     /// Retrieve the latest head of the blockchain.
@@ -23,4 +23,71 @@ pub trait ChainProvider {
     /// Retrieve the account balances pertaining to the last block retrieved
     /// from the chain.
     async fn balances(&self) -> Result<Vec<Account>, Self::Error>;
+
+    /// Retrieve the withdrawals from validators pertaining to the last block
+    /// retrieved from the chain.
+    async fn withdrawals(&self) -> Result<Vec<Withdrawal>, Self::Error> {
+        Ok(Vec::new())
+    }
+
+    /// Retrieve fee suggestions for transactions to be included after the last
+    /// block retrieved from the chain, if the chain supports them.
+    async fn fees(&self) -> Result<Option<Fees>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Retrieve the transactions currently pending inclusion in a block, if
+    /// the chain exposes its transaction pool.
+    ///
+    /// The whole set of pending transactions is returned each time.
+    async fn pending_transactions(&self) -> Result<Option<Vec<Transaction>>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Retrieve the pending transaction sent from an address with the given
+    /// nonce, if it is known to the node.
+    async fn pending_transaction(
+        &self,
+        address: &str,
+        nonce: u64,
+    ) -> Result<Option<Transaction>, Self::Error> {
+        let _ = (address, nonce); // to appease clippy
+        Ok(None)
+    }
+
+    /// Resolve any human-readable names in the list of watched accounts,
+    /// returning the names which could not be resolved and why.
+    ///
+    /// Called before the first retrieval of balances and periodically thereafter
+    /// so that names which are repointed are followed. A name which cannot be
+    /// resolved keeps the address it last resolved to.
+    async fn resolve_watchlist(&mut self) -> Vec<(String, Self::Error)> {
+        Vec::new()
+    }
+
+    /// Start watching the account at an address or human-readable name, from
+    /// the next retrieval of balances.
+    async fn watch(&mut self, account: &str) -> Result<(), Self::Error> {
+        let _ = account; // to appease clippy
+        Ok(())
+    }
+
+    /// Stop watching the account at an address or human-readable name.
+    async fn unwatch(&mut self, account: &str) -> Result<(), Self::Error> {
+        let _ = account; // to appease clippy
+        Ok(())
+    }
+
+    /// Retrieve chain data from a different endpoint from now on.
+    async fn set_endpoint(&mut self, url: &str) -> Result<(), Self::Error> {
+        let _ = url; // to appease clippy
+        Ok(())
+    }
+
+    /// Retrieve the human-readable name of an address, if the chain supports
+    /// naming and one is registered.
+    async fn lookup_name(&self, address: &str) -> Result<Option<String>, Self::Error> {
+        let _ = address; // to appease clippy
+        Ok(None)
+    }
 }
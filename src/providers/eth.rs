@@ -1,16 +1,26 @@
+pub mod ens;
+
 use crate::providers::{Account, Block, ChainProvider, Transaction};
+use crate::types::{Amount, BlobUsage, Blobs, Fees, TxKind, Withdrawal};
 use alloy::consensus::Transaction as AlloyTransaction;
-use alloy::eips::BlockId;
+use alloy::eips::{eip4844::DATA_GAS_PER_BLOB, eip7840::BlobParams, BlockId, BlockNumberOrTag};
 use alloy::hex::FromHexError;
-use alloy::primitives::utils::format_units;
-use alloy::primitives::{Address as AlloyAddress, Uint};
-use alloy::providers::{DynProvider, Provider, ProviderBuilder};
-use alloy::rpc::types::Block as AlloyBlock;
+use alloy::primitives::{Address as AlloyAddress, Bytes, U256};
+use alloy::providers::{ext::TxPoolApi, DynProvider, Provider, ProviderBuilder};
+use alloy::rpc::types::{Block as AlloyBlock, FeeHistory, Transaction as AlloyRpcTransaction};
 use alloy::transports::{RpcError, TransportErrorKind};
 use config::ConfigError;
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
+use tracing::warn;
 use url::Url;
 
+/// Number of recent blocks over which priority fees are sampled.
+const FEE_HISTORY_BLOCKS: u64 = 20;
+
+/// Percentiles of the priority fees paid in each block which inform slow,
+/// standard and fast fee suggestions.
+const FEE_PERCENTILES: [f64; 3] = [10.0, 50.0, 90.0];
+
 #[derive(thiserror::Error, Debug)]
 pub enum EthProviderError {
     #[error("invalid content in configuration file")]
@@ -22,6 +32,12 @@ pub enum EthProviderError {
     #[error(transparent)]
     Transport(#[from] RpcError<TransportErrorKind>),
 
+    #[error(transparent)]
+    Contract(#[from] alloy::contract::Error),
+
+    #[error("invalid endpoint url specified")]
+    InvalidUrl(#[from] url::ParseError),
+
     #[error("head block could not be found")]
     NoHead,
 }
@@ -31,22 +47,44 @@ pub struct EthProvider {
     provider: DynProvider,
     head: Option<AlloyBlock>,
     addrs: Vec<AlloyAddress>,
+    /// ENS names of watched accounts and the addresses they last resolved to.
+    names: Vec<(String, Option<AlloyAddress>)>,
 }
 
 impl EthProvider {
+    /// Constructs a provider watching the given accounts.
+    ///
+    /// Accounts can be specified as hex addresses or ENS names. Names are not
+    /// resolved until [`ChainProvider::resolve_watchlist`] is called.
     pub fn new(url: Url, addrs: &[String]) -> Result<Self, EthProviderError> {
+        let (names, addrs): (Vec<_>, Vec<_>) = addrs.iter().partition(|a| ens::is_name(a));
         let addrs = addrs
-            .iter()
+            .into_iter()
             .map(|a| AlloyAddress::from_str(a.as_str()))
             .collect::<Result<Vec<_>, _>>()?;
-        let provider = ProviderBuilder::new().on_http(url);
-        let provider = DynProvider::new(provider);
+        let names = names
+            .into_iter()
+            .map(|n| (ens::normalize(n), None))
+            .collect();
         Ok(Self {
-            provider,
+            provider: Self::connect(url),
             addrs,
+            names,
             head: None,
         })
     }
+
+    fn connect(url: Url) -> DynProvider {
+        DynProvider::new(ProviderBuilder::new().on_http(url))
+    }
+
+    /// Returns the addresses of all watched accounts, including those
+    /// specified by resolved ENS names.
+    fn watched(&self) -> impl Iterator<Item = &AlloyAddress> {
+        self.addrs
+            .iter()
+            .chain(self.names.iter().filter_map(|(_, addr)| addr.as_ref()))
+    }
 }
 
 impl From<&AlloyBlock> for Block {
@@ -55,6 +93,121 @@ impl From<&AlloyBlock> for Block {
             number: block.header.number,
             timestamp: block.header.timestamp,
             hash: block.header.hash.to_string(),
+            base_fee: block.header.base_fee_per_gas,
+            gas_used: block.header.gas_used,
+            gas_limit: block.header.gas_limit,
+            blob_gas_used: block.header.blob_gas_used,
+            excess_blob_gas: block.header.excess_blob_gas,
+            transactions: block.transactions.len(),
+            size: block.header.size.map(|size| size.saturating_to()),
+            fee_recipient: block.header.beneficiary.to_string(),
+            extra_data: decode_extra_data(&block.header.extra_data),
+            block_time: None,
+            blob_usage: blob_usage(block),
+        }
+    }
+}
+
+/// Works out the blob usage of a block and its blob base fee from the excess
+/// blob gas.
+fn blob_usage(block: &AlloyBlock) -> Option<BlobUsage> {
+    let header = &block.header;
+    let (blob_gas_used, excess_blob_gas) = (header.blob_gas_used?, header.excess_blob_gas?);
+    // Blob limits were raised by Prague, which also introduced requests.
+    let params = if header.requests_hash.is_some() {
+        BlobParams::prague()
+    } else {
+        BlobParams::cancun()
+    };
+    Some(BlobUsage {
+        blobs: blob_gas_used / DATA_GAS_PER_BLOB,
+        target: params.target_blob_count,
+        max: params.max_blob_count,
+        base_fee: params.calc_blob_fee(excess_blob_gas),
+    })
+}
+
+/// Suggests fees from the history of recent blocks. The priority fee of each
+/// tier is the median across blocks of the corresponding percentile.
+fn suggest_fees(history: &FeeHistory, block: u64, suggested: u128) -> Fees {
+    let fee = |fee: u128| u64::try_from(fee).unwrap_or(u64::MAX);
+    let tier = |i: usize| {
+        let mut rewards: Vec<_> = history
+            .reward
+            .iter()
+            .flatten()
+            .filter_map(|rewards| rewards.get(i).copied())
+            .collect();
+        rewards.sort_unstable();
+        rewards
+            .get(rewards.len() / 2)
+            .copied()
+            .map(fee)
+            .unwrap_or_default()
+    };
+    Fees {
+        block,
+        next_base_fee: history.next_block_base_fee().map(fee).unwrap_or_default(),
+        slow: tier(0),
+        standard: tier(1),
+        fast: tier(2),
+        suggested: fee(suggested),
+    }
+}
+
+/// Totals the withdrawals to each address in a block, in wei.
+fn withdrawn(block: &AlloyBlock) -> HashMap<AlloyAddress, U256> {
+    let mut withdrawn = HashMap::<AlloyAddress, U256>::new();
+    for withdrawal in block.withdrawals.iter().flatten() {
+        *withdrawn.entry(withdrawal.address).or_default() += withdrawal.amount_wei();
+    }
+    withdrawn
+}
+
+/// Returns true for the JSON-RPC error codes of methods which the node does
+/// not know (-32601) or does not support (-32004).
+fn is_unsupported_method(code: i64) -> bool {
+    matches!(code, -32601 | -32004)
+}
+
+/// Decodes the extra data of a block, which builders typically use to sign
+/// their name, as text if it is printable and hex otherwise.
+fn decode_extra_data(extra_data: &Bytes) -> String {
+    match std::str::from_utf8(extra_data) {
+        Ok(text) if !text.chars().any(char::is_control) => text.trim().to_string(),
+        _ => extra_data.to_string(),
+    }
+}
+
+impl From<&AlloyRpcTransaction> for Transaction {
+    fn from(tx: &AlloyRpcTransaction) -> Self {
+        Self {
+            block: tx.block_number.unwrap_or_default(),
+            nonce: tx.inner.nonce(),
+            kind: TxKind::from(tx.inner.tx_type() as u8),
+            value: ether(tx.inner.value()),
+            // Mined transactions have an effective gas price. Pending ones
+            // pay at most their max fee.
+            gas_price: tx
+                .effective_gas_price
+                .unwrap_or_else(|| tx.inner.max_fee_per_gas()),
+            // Set once the block and receipt of the transaction are known.
+            tip: None,
+            gas_used: None,
+            failed: false,
+            blobs: tx.inner.blob_versioned_hashes().map(|hashes| Blobs {
+                versioned_hashes: hashes.iter().map(ToString::to_string).collect(),
+                max_fee_per_blob_gas: tx.inner.max_fee_per_blob_gas().unwrap_or_default(),
+                blob_gas: hashes.len() as u64 * DATA_GAS_PER_BLOB,
+                cost: None,
+            }),
+            hash: tx.inner.tx_hash().to_string(),
+            from: tx.inner.signer().to_string(),
+            to: tx
+                .inner
+                .to()
+                .map(|addr| addr.to_string())
+                .unwrap_or_default(),
         }
     }
 }
@@ -71,7 +224,22 @@ impl ChainProvider for EthProvider {
         let block = block.ok_or(EthProviderError::NoHead)?;
 
         // Convert to block types required by the UI.
-        let result_block = (&block).into();
+        let mut result_block = Block::from(&block);
+
+        // Work out the time since the parent block, retrieving the parent if
+        // it is not the last block retrieved.
+        let parent_timestamp = match &self.head {
+            Some(head) if head.header.hash == block.header.parent_hash => {
+                Some(head.header.timestamp)
+            }
+            _ => self
+                .provider
+                .get_block_by_hash(block.header.parent_hash)
+                .await?
+                .map(|parent| parent.header.timestamp),
+        };
+        result_block.block_time =
+            parent_timestamp.map(|timestamp| block.header.timestamp.saturating_sub(timestamp));
 
         // Keep track of latest block.
         self.head = block.into();
@@ -82,32 +250,63 @@ impl ChainProvider for EthProvider {
 
     /// Returns transactions pertaining to the latest block retrieved from the chain.
     async fn transactions(&self) -> Result<Vec<Transaction>, Self::Error> {
-        if let Some(block) = &self.head {
-            // Map the transactions in latest block to transaction types
-            // required by the UI.
-            let txs: Vec<_> = block
-                .transactions
-                .as_transactions()
+        let Some(block) = &self.head else {
+            return Err(EthProviderError::NoHead);
+        };
+
+        // Gas used and failures are only known from receipts. Not all nodes
+        // serve block receipts, so do without them if they cannot be
+        // retrieved.
+        let receipts: HashMap<_, _> = match self
+            .provider
+            .get_block_receipts(BlockId::from(block.header.number))
+            .await
+        {
+            Ok(receipts) => receipts
                 .into_iter()
                 .flatten()
-                .map(|tx| Transaction {
-                    nonce: tx.inner.nonce().to_string(),
-                    kind: tx.inner.tx_type().to_string(),
-                    units: "gwei".to_string(),
-                    value: gwei(tx.inner.value()),
-                    hash: tx.inner.tx_hash().to_string(),
-                    from: tx.inner.signer().to_string(),
-                    to: tx
-                        .inner
-                        .to()
-                        .map(|addr| addr.to_string())
-                        .unwrap_or_default(),
+                .map(|receipt| {
+                    (
+                        receipt.transaction_hash,
+                        (receipt.gas_used, !receipt.status()),
+                    )
                 })
-                .collect();
-            Ok(txs)
-        } else {
-            Err(EthProviderError::NoHead)
-        }
+                .collect(),
+            Err(e) => {
+                warn!(
+                    "Failed to get receipts of block {}: {e}",
+                    block.header.number
+                );
+                HashMap::new()
+            }
+        };
+
+        // Map the transactions in latest block to transaction types
+        // required by the UI.
+        let base_fee = block.header.base_fee_per_gas.unwrap_or_default();
+        let blob_base_fee = blob_usage(block).map(|usage| usage.base_fee);
+        let txs: Vec<_> = block
+            .transactions
+            .as_transactions()
+            .into_iter()
+            .flatten()
+            .map(|tx| {
+                let mut result = Transaction::from(tx);
+                result.tip = tx
+                    .inner
+                    .effective_tip_per_gas(base_fee)
+                    .map(|tip| u64::try_from(tip).unwrap_or(u64::MAX));
+                if let Some((gas_used, failed)) = receipts.get(tx.inner.tx_hash()) {
+                    result.gas_used = Some(*gas_used);
+                    result.failed = *failed;
+                }
+                if let (Some(blobs), Some(fee)) = (result.blobs.as_mut(), blob_base_fee) {
+                    blobs.cost = Some(fee * blobs.blob_gas as u128);
+                }
+                result
+            })
+            .collect();
+        Ok(txs)
     }
 
     /// Retrieves balances pertaining to the latest block or the last
@@ -120,32 +319,189 @@ impl ChainProvider for EthProvider {
             .map(|b| BlockId::from(b.header.number))
             .unwrap_or(BlockId::latest());
 
+        let withdrawn = self.head.as_ref().map(withdrawn).unwrap_or_default();
+
         // Map the accounts to UI data elements.
         let mut accounts = Vec::new();
-        for addr in &self.addrs {
+        for addr in self.watched() {
             let bal = self.provider.get_balance(*addr).block_id(block).await?;
+            let nonce = self
+                .provider
+                .get_transaction_count(*addr)
+                .block_id(block)
+                .await?;
+            let pending_nonce = self.provider.get_transaction_count(*addr).pending().await?;
             accounts.push(Account {
-                balance: gwei(bal),
+                balance: ether(bal),
                 address: addr.to_string(),
-                units: "gwei".to_string(),
+                nonce,
+                // The pending nonce can lag behind when the node is behind the
+                // block retrieved.
+                pending_nonce: pending_nonce.max(nonce),
+                stuck: None,
+                withdrawn: withdrawn.get(addr).copied().map(ether),
             });
         }
         Ok(accounts)
     }
+
+    /// Returns the withdrawals pertaining to the latest block retrieved from
+    /// the chain.
+    async fn withdrawals(&self) -> Result<Vec<Withdrawal>, Self::Error> {
+        let Some(block) = &self.head else {
+            return Err(EthProviderError::NoHead);
+        };
+        let withdrawals = block
+            .withdrawals
+            .iter()
+            .flatten()
+            .map(|withdrawal| Withdrawal {
+                index: withdrawal.index,
+                validator: withdrawal.validator_index,
+                address: withdrawal.address.to_string(),
+                amount: ether(withdrawal.amount_wei()),
+            })
+            .collect();
+        Ok(withdrawals)
+    }
+
+    /// Suggests fees from the priority fees paid in recent blocks and the
+    /// node's own suggestion.
+    async fn fees(&self) -> Result<Option<Fees>, Self::Error> {
+        let Some(head) = &self.head else {
+            return Err(EthProviderError::NoHead);
+        };
+        // Chains without a base fee have no fee market to estimate.
+        if head.header.base_fee_per_gas.is_none() {
+            return Ok(None);
+        }
+        let number = head.header.number;
+        let history = self
+            .provider
+            .get_fee_history(
+                FEE_HISTORY_BLOCKS,
+                BlockNumberOrTag::Number(number),
+                &FEE_PERCENTILES,
+            )
+            .await?;
+        let suggested = self.provider.get_max_priority_fee_per_gas().await?;
+        Ok(Some(suggest_fees(&history, number, suggested)))
+    }
+
+    /// Retrieves the pending transactions of the node's transaction pool, or
+    /// none if the node does not support `txpool_content`.
+    async fn pending_transactions(&self) -> Result<Option<Vec<Transaction>>, Self::Error> {
+        let content = match self.provider.txpool_content().await {
+            Ok(content) => content,
+            Err(RpcError::ErrorResp(e)) if is_unsupported_method(e.code) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let txs = content
+            .pending
+            .values()
+            .flat_map(|txs| txs.values())
+            .map(Transaction::from)
+            .collect();
+        Ok(Some(txs))
+    }
+
+    /// Finds the transaction in the node's transaction pool sent from the
+    /// address with the given nonce.
+    async fn pending_transaction(
+        &self,
+        address: &str,
+        nonce: u64,
+    ) -> Result<Option<Transaction>, Self::Error> {
+        let addr = AlloyAddress::from_str(address)?;
+        let content = self.provider.txpool_content_from(addr).await?;
+        let tx = content
+            .pending
+            .values()
+            .find(|tx| tx.inner.nonce() == nonce)
+            .map(Transaction::from);
+        Ok(tx)
+    }
+
+    /// Resolves the ENS names in the watch list to their current addresses.
+    async fn resolve_watchlist(&mut self) -> Vec<(String, Self::Error)> {
+        let mut failures = Vec::new();
+        for (name, addr) in self.names.iter_mut() {
+            match ens::resolve(&self.provider, name).await {
+                Ok(resolved) => *addr = resolved,
+                Err(e) => failures.push((name.clone(), e.into())),
+            }
+        }
+        failures
+    }
+
+    async fn watch(&mut self, account: &str) -> Result<(), Self::Error> {
+        if ens::is_name(account) {
+            let account = ens::normalize(account);
+            if !self.names.iter().any(|(name, _)| *name == account) {
+                let addr = ens::resolve(&self.provider, &account).await?;
+                self.names.push((account, addr));
+            }
+        } else {
+            let addr = AlloyAddress::from_str(account)?;
+            if !self.addrs.contains(&addr) {
+                self.addrs.push(addr);
+            }
+        }
+        Ok(())
+    }
+
+    async fn unwatch(&mut self, account: &str) -> Result<(), Self::Error> {
+        match AlloyAddress::from_str(account) {
+            // Accounts specified by name are unwatched by their address too.
+            Ok(addr) => {
+                self.addrs.retain(|a| *a != addr);
+                self.names.retain(|(_, a)| *a != Some(addr));
+            }
+            Err(_) => {
+                let account = ens::normalize(account);
+                self.names.retain(|(name, _)| *name != account);
+            }
+        }
+        Ok(())
+    }
+
+    async fn set_endpoint(&mut self, url: &str) -> Result<(), Self::Error> {
+        self.provider = Self::connect(url.parse()?);
+        self.head = None;
+        Ok(())
+    }
+
+    /// Performs a forward-verified ENS reverse lookup of the address.
+    async fn lookup_name(&self, address: &str) -> Result<Option<String>, Self::Error> {
+        let addr = AlloyAddress::from_str(address)?;
+        Ok(ens::lookup(&self.provider, addr).await?)
+    }
 }
 
-/// Formats a U256 into gwei string.
-fn gwei(num: Uint<256, 4>) -> String {
-    format_units(num, "gwei").expect("gwei is valid unit format")
+/// Wraps an amount of wei into an amount of ether.
+fn ether(wei: U256) -> Amount {
+    Amount::new(wei, 18, "ETH")
 }
 
 #[cfg(test)]
 mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
     use url::Url;
 
-    use crate::providers::eth::EthProviderError;
+    use crate::providers::{eth::EthProviderError, ChainProvider};
+
+    use alloy::eips::eip4895::{Withdrawal as AlloyWithdrawal, Withdrawals};
+    use alloy::primitives::{B256, U256};
+    use alloy::rpc::types::FeeHistory;
 
-    use super::EthProvider;
+    use super::{
+        blob_usage, decode_extra_data, ether, suggest_fees, withdrawn, AlloyAddress, AlloyBlock,
+        EthProvider,
+    };
+    use crate::types::Fees;
     #[test]
     fn instantiate() {
         let u = Url::parse("http://localhost:8545").unwrap();
@@ -171,4 +527,145 @@ mod tests {
         let e = EthProvider::new(u, &addrs);
         assert!(matches!(e, Err(EthProviderError::InvalidAddress(_))));
     }
+
+    #[test]
+    fn instantiate_names() {
+        let u = Url::parse("http://localhost:8545").unwrap();
+        let addrs = vec![
+            "Vitalik.eth".to_string(),
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045".to_string(),
+        ];
+        let p = EthProvider::new(u, &addrs).unwrap();
+        // Names are normalised.
+        assert_eq!(p.addrs.len(), 1);
+        assert_eq!(p.names, vec![("vitalik.eth".to_string(), None)]);
+        // Unresolved names are not watched.
+        assert_eq!(p.watched().count(), 1);
+    }
+
+    #[test]
+    fn extra_data() {
+        assert_eq!(
+            decode_extra_data(&b"beaverbuild.org".into()),
+            "beaverbuild.org"
+        );
+        assert_eq!(decode_extra_data(&b"\xd8\x83\x01".into()), "0xd88301");
+        assert_eq!(decode_extra_data(&b"geth\x01".into()), "0x6765746801");
+    }
+
+    #[test]
+    fn fees() {
+        let history = FeeHistory {
+            base_fee_per_gas: vec![10, 12, 11],
+            reward: Some(vec![vec![1, 5, 9], vec![2, 6, 30], vec![0, 4, 8]]),
+            ..Default::default()
+        };
+        assert_eq!(
+            suggest_fees(&history, 7, 3),
+            Fees {
+                block: 7,
+                next_base_fee: 11,
+                slow: 1,
+                standard: 5,
+                fast: 9,
+                suggested: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn blobs() {
+        let mut block = AlloyBlock::default();
+        assert_eq!(blob_usage(&block), None);
+
+        block.header.inner.blob_gas_used = Some(3 * 131_072);
+        block.header.inner.excess_blob_gas = Some(0);
+        let usage = blob_usage(&block).unwrap();
+        assert_eq!((usage.blobs, usage.target, usage.max), (3, 3, 6));
+        assert_eq!(usage.base_fee, 1);
+
+        // Prague raised the limits.
+        block.header.inner.requests_hash = Some(B256::ZERO);
+        let usage = blob_usage(&block).unwrap();
+        assert_eq!((usage.target, usage.max), (6, 9));
+    }
+
+    /// A block with withdrawals of the given amounts in gwei to addresses.
+    fn block_with_withdrawals(withdrawals: &[(AlloyAddress, u64)]) -> AlloyBlock {
+        let withdrawals = withdrawals
+            .iter()
+            .enumerate()
+            .map(|(i, (address, amount))| AlloyWithdrawal {
+                index: i as u64,
+                validator_index: 100 + i as u64,
+                address: *address,
+                amount: *amount,
+            })
+            .collect();
+        AlloyBlock {
+            withdrawals: Some(Withdrawals::new(withdrawals)),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn withdrawals() {
+        let u = Url::parse("http://localhost:8545").unwrap();
+        let mut provider = EthProvider::new(u, &[]).unwrap();
+        let address = AlloyAddress::repeat_byte(1);
+        provider.head = Some(block_with_withdrawals(&[(address, 1_500_000_000)]));
+        let withdrawals = provider.withdrawals().await.unwrap();
+        assert_eq!(withdrawals.len(), 1);
+        assert_eq!(withdrawals[0].validator, 100);
+        assert_eq!(withdrawals[0].address, address.to_string());
+        // Amounts are given in gwei and displayed in ether.
+        assert_eq!(
+            withdrawals[0].amount,
+            ether(U256::from(1_500_000_000_000_000_000u128))
+        );
+    }
+
+    #[test]
+    fn withdrawn_totals() {
+        let (a, b) = (AlloyAddress::repeat_byte(1), AlloyAddress::repeat_byte(2));
+        let block = block_with_withdrawals(&[(a, 1), (b, 2), (a, 3)]);
+        let withdrawn = withdrawn(&block);
+        assert_eq!(withdrawn.len(), 2);
+        assert_eq!(withdrawn[&a], U256::from(4_000_000_000u64));
+        assert_eq!(withdrawn[&b], U256::from(2_000_000_000u64));
+        assert!(super::withdrawn(&AlloyBlock::default()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn txpool_unsupported() {
+        // Answer a single request as a node without the txpool namespace would.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !String::from_utf8_lossy(&request).ends_with('}') {
+                let n = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            let request = String::from_utf8(request).unwrap();
+            let body = request.split("\r\n\r\n").nth(1).unwrap();
+            let id = serde_json::from_str::<serde_json::Value>(body).unwrap()["id"].clone();
+            let body = serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": -32601, "message": "the method txpool_content does not exist/is not available" },
+            })
+            .to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        let provider = EthProvider::new(Url::parse(&url).unwrap(), &[]).unwrap();
+        assert!(provider.pending_transactions().await.unwrap().is_none());
+    }
 }
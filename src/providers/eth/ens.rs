@@ -0,0 +1,133 @@
+use alloy::primitives::{address, keccak256, Address as AlloyAddress, B256};
+use alloy::providers::DynProvider;
+use alloy::sol;
+
+/// Address of the ENS registry, which is the same on mainnet and the major testnets.
+const ENS_REGISTRY: AlloyAddress = address!("00000000000C2E074eC69A0dFb2997BA6C7d2e1e");
+
+sol! {
+    #[sol(rpc)]
+    interface EnsRegistry {
+        function resolver(bytes32 node) external view returns (address);
+    }
+
+    #[sol(rpc)]
+    interface EnsResolver {
+        function addr(bytes32 node) external view returns (address);
+        function name(bytes32 node) external view returns (string);
+    }
+}
+
+/// Normalises a name so that names differing only in case are the same
+/// name. This covers the ASCII names in common use, not all of ENSIP-15.
+pub fn normalize(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+/// Computes the ENS namehash of a name as per EIP-137, after normalising it.
+pub fn namehash(name: &str) -> B256 {
+    let mut node = B256::ZERO;
+    let name = normalize(name);
+    if name.is_empty() {
+        return node;
+    }
+    for label in name.rsplit('.') {
+        let mut buf = [0u8; 64];
+        buf[..32].copy_from_slice(node.as_slice());
+        buf[32..].copy_from_slice(keccak256(label.as_bytes()).as_slice());
+        node = keccak256(buf);
+    }
+    node
+}
+
+/// Returns true if the content looks like an ENS name rather than a hex address.
+pub fn is_name(s: &str) -> bool {
+    !s.starts_with("0x") && s.contains('.')
+}
+
+/// Retrieves the resolver contract registered for an ENS node, if there is one.
+async fn resolver(
+    provider: &DynProvider,
+    node: B256,
+) -> Result<Option<AlloyAddress>, alloy::contract::Error> {
+    let registry = EnsRegistry::new(ENS_REGISTRY, provider);
+    let resolver = registry.resolver(node).call().await?._0;
+    Ok((!resolver.is_zero()).then_some(resolver))
+}
+
+/// Resolves an ENS name to the address it points to.
+pub async fn resolve(
+    provider: &DynProvider,
+    name: &str,
+) -> Result<Option<AlloyAddress>, alloy::contract::Error> {
+    let node = namehash(name);
+    let Some(resolver) = resolver(provider, node).await? else {
+        return Ok(None);
+    };
+    let addr = EnsResolver::new(resolver, provider)
+        .addr(node)
+        .call()
+        .await?
+        ._0;
+    Ok((!addr.is_zero()).then_some(addr))
+}
+
+/// Performs a reverse lookup of the primary ENS name of an address.
+///
+/// The name is only returned if it resolves back to the same address. Reverse
+/// records can be set to arbitrary names by the owner of the address, so the
+/// forward check prevents an account from impersonating another one.
+pub async fn lookup(
+    provider: &DynProvider,
+    addr: AlloyAddress,
+) -> Result<Option<String>, alloy::contract::Error> {
+    let reverse = format!("{}.addr.reverse", alloy::hex::encode(addr));
+    let node = namehash(&reverse);
+    let Some(resolver) = resolver(provider, node).await? else {
+        return Ok(None);
+    };
+    let name = EnsResolver::new(resolver, provider)
+        .name(node)
+        .call()
+        .await?
+        ._0;
+    if name.is_empty() {
+        return Ok(None);
+    }
+
+    // Verify the forward record.
+    match resolve(provider, &name).await? {
+        Some(forward) if forward == addr => Ok(Some(name)),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::b256;
+
+    use super::{is_name, namehash, normalize};
+
+    #[test]
+    fn namehash_vectors() {
+        assert_eq!(namehash(""), alloy::primitives::B256::ZERO);
+        assert_eq!(
+            namehash("eth"),
+            b256!("93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae")
+        );
+        assert_eq!(
+            namehash("foo.eth"),
+            b256!("de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f")
+        );
+        assert_eq!(namehash("Foo.ETH"), namehash("foo.eth"));
+        assert_eq!(normalize(" Vitalik.eth"), "vitalik.eth");
+    }
+
+    #[test]
+    fn names() {
+        assert!(is_name("vitalik.eth"));
+        assert!(is_name("sub.domain.xyz"));
+        assert!(!is_name("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"));
+        assert!(!is_name("vitalik"));
+    }
+}
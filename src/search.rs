@@ -0,0 +1,172 @@
+use std::cmp::Ordering;
+
+use alloy::primitives::{utils::parse_units, U256};
+
+use crate::{labels::AddressBook, names::Names, types::Transaction};
+
+/// A search over transactions, made up of terms which must all match.
+///
+/// A term is either a comparison of the value, such as `value>10eth` or
+/// `value<=5gwei`, or text matched against the hash prefix, the from and to
+/// addresses and their labels or names, and the kind of a transaction.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Query {
+    terms: Vec<Term>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Term {
+    /// Text matched case-insensitively.
+    Text(String),
+    /// Orderings of the value relative to an amount in the smallest unit of
+    /// the native currency which match.
+    Value(Vec<Ordering>, U256),
+}
+
+impl Query {
+    /// Parses a query, failing on malformed value comparisons.
+    pub fn parse(query: &str) -> Result<Self, String> {
+        let terms = query
+            .split_whitespace()
+            .map(|term| parse_value(term).unwrap_or_else(|| Ok(Term::Text(term.to_lowercase()))))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { terms })
+    }
+
+    /// Returns true if the query has no terms, and so matches nothing.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Returns true if the transaction matches every term of the query.
+    pub fn matches(&self, tx: &Transaction, labels: &AddressBook, names: &Names) -> bool {
+        !self.is_empty()
+            && self.terms.iter().all(|term| match term {
+                Term::Text(text) => {
+                    tx.hash.to_lowercase().starts_with(text)
+                        || tx.kind.to_string().to_lowercase().contains(text)
+                        || [&tx.from, &tx.to]
+                            .iter()
+                            .any(|address| address_matches(address, text, labels, names))
+                }
+                Term::Value(orderings, amount) => orderings.contains(&tx.value.value.cmp(amount)),
+            })
+    }
+}
+
+/// Returns true if the address starts with the text, or its label or name
+/// contains it.
+fn address_matches(address: &str, text: &str, labels: &AddressBook, names: &Names) -> bool {
+    !address.is_empty()
+        && (address.to_lowercase().starts_with(text)
+            || labels
+                .get(address)
+                .is_some_and(|label| label.name.to_lowercase().contains(text))
+            || names
+                .get(address)
+                .is_some_and(|name| name.to_lowercase().contains(text)))
+}
+
+/// Parses a comparison of the value, such as `value>10eth`, or returns None
+/// if the term is not one. Amounts without units are in ether.
+fn parse_value(term: &str) -> Option<Result<Term, String>> {
+    let operators = [
+        (">=", vec![Ordering::Greater, Ordering::Equal]),
+        ("<=", vec![Ordering::Less, Ordering::Equal]),
+        (">", vec![Ordering::Greater]),
+        ("<", vec![Ordering::Less]),
+        ("=", vec![Ordering::Equal]),
+    ];
+    let comparison = term.strip_prefix("value")?;
+    let (orderings, amount) = operators.into_iter().find_map(|(operator, orderings)| {
+        comparison
+            .strip_prefix(operator)
+            .map(|amount| (orderings, amount))
+    })?;
+    let lower = amount.to_lowercase();
+    let (number, units) = match lower.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(i) => lower.split_at(i),
+        None => (lower.as_str(), "ether"),
+    };
+    let units = match units {
+        "eth" => "ether",
+        units => units,
+    };
+    let value = match parse_units(number, units) {
+        Ok(value) if value.is_negative() => {
+            Err(format!("Invalid amount {amount}: must not be negative"))
+        }
+        Ok(value) => Ok(Term::Value(orderings, value.get_absolute())),
+        Err(e) => Err(format!("Invalid amount {amount}: {e}")),
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::U256;
+
+    use super::{Query, Term};
+    use crate::{
+        labels::{AddressBook, Label},
+        names::Names,
+        types::{Amount, Transaction, TxKind},
+    };
+
+    #[test]
+    fn matches() {
+        let labels = AddressBook::default();
+        labels.set(
+            "0xbbbb",
+            Some(Label {
+                name: "Exchange".to_string(),
+                color: None,
+            }),
+        );
+        let names = Names::default();
+        names.set("0xcccc", Some("vitalik.eth".to_string()));
+        let tx = Transaction {
+            hash: "0xABCD".to_string(),
+            from: "0xaaaa".to_string(),
+            to: "0xbbbb".to_string(),
+            kind: TxKind::Blob,
+            value: Amount::new(U256::from(2_000_000_000_000_000_000u64), 18, "ETH"),
+            ..Default::default()
+        };
+        let matches = |query: &str| Query::parse(query).unwrap().matches(&tx, &labels, &names);
+        assert!(matches("0xabc"));
+        assert!(matches("0xAAA"));
+        assert!(matches("exch"));
+        assert!(matches("4844"));
+        assert!(matches("value>1.5eth"));
+        assert!(matches("value>=2 exchange"));
+        assert!(matches("value<3000000000gwei"));
+        assert!(!matches("value>2eth"));
+        assert!(!matches("0xabc vitalik"));
+        assert!(!matches(""));
+
+        let tx = Transaction {
+            to: "0xcccc".to_string(),
+            ..tx
+        };
+        assert!(Query::parse("vitalik")
+            .unwrap()
+            .matches(&tx, &labels, &names));
+
+        assert!(Query::parse("value>1furlong").is_err());
+        assert!(Query::parse("value>-1eth").is_err());
+        assert!(Query::parse("value<=-0.5").is_err());
+    }
+
+    #[test]
+    fn value_words_are_text() {
+        for term in ["valuable", "value-vault", "value~1", "value"] {
+            assert_eq!(
+                Query::parse(term).unwrap(),
+                Query {
+                    terms: vec![Term::Text(term.to_string())]
+                }
+            );
+        }
+    }
+}
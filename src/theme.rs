@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+
+use ratatui::style::{Color, Style, Stylize};
+use serde::{Deserialize, Serialize};
+use strum::{EnumString, VariantNames};
+use tracing::warn;
+
+use crate::config::Styles;
+
+/// A built-in set of styles, which styles in the config override.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, EnumString, VariantNames,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum ThemeName {
+    #[default]
+    Default,
+    /// Bright colors, bold text and filled highlights.
+    HighContrast,
+    /// No colors, telling parts apart by bold, underlined and reversed text.
+    Monochrome,
+}
+
+/// Styles of the parts of the panels, by key such as `txlist.watched_row`.
+#[derive(Clone, Debug)]
+pub struct Theme {
+    styles: HashMap<String, Style>,
+    /// Whether colors may be drawn, which they may not if `NO_COLOR` is set
+    /// and no theme is configured.
+    pub colors: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::named(ThemeName::Default)
+    }
+}
+
+impl Theme {
+    /// Returns a built-in theme.
+    pub fn named(name: ThemeName) -> Self {
+        let mut styles = default_styles();
+        match name {
+            ThemeName::Default => {}
+            ThemeName::HighContrast => styles.extend(high_contrast_styles()),
+            ThemeName::Monochrome => {
+                for style in styles.values_mut() {
+                    *style = without_colors(*style);
+                }
+                styles.extend(monochrome_styles());
+            }
+        }
+        Self {
+            styles: styles
+                .into_iter()
+                .map(|(key, style)| (key.to_string(), style))
+                .collect(),
+            colors: true,
+        }
+    }
+
+    /// Returns the configured theme with the configured styles on top.
+    ///
+    /// Without a configured theme, the monochrome theme is used if the
+    /// `NO_COLOR` environment variable is set, and colors are left out
+    /// entirely. Styles of unknown keys are ignored with a warning.
+    pub fn new(name: Option<ThemeName>, styles: &Styles, no_color: bool) -> Self {
+        let colors = name.is_some() || !no_color;
+        let mut theme = Self::named(name.unwrap_or(if colors {
+            ThemeName::Default
+        } else {
+            ThemeName::Monochrome
+        }));
+        theme.colors = colors;
+        for (key, style) in styles.iter() {
+            match theme.styles.get_mut(key) {
+                Some(existing) => *existing = *style,
+                None => warn!("Ignoring style of unknown key {key}"),
+            }
+        }
+        theme
+    }
+
+    /// Returns the style of a key, or the default style if it is unknown.
+    pub fn get(&self, key: &str) -> Style {
+        self.styles.get(key).copied().unwrap_or_default()
+    }
+}
+
+/// Returns true if the `NO_COLOR` environment variable asks for no colors.
+pub fn no_color() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+/// Returns the style with its foreground and background colors reset.
+pub fn without_colors(style: Style) -> Style {
+    Style {
+        fg: style.fg.map(|_| Color::Reset),
+        bg: style.bg.map(|_| Color::Reset),
+        underline_color: None,
+        ..style
+    }
+}
+
+/// Styles of every key in the default theme.
+fn default_styles() -> HashMap<&'static str, Style> {
+    let header = Style::new().bold().italic();
+    let selected = Style::new().reversed();
+    HashMap::from([
+        ("focused_border", Style::new().bold()),
+        ("head.border", Style::new().blue()),
+        ("head.selected_row", selected),
+        ("head.gauge_filled", Style::new().blue()),
+        ("head.gauge_unfilled", Style::new().dark_gray()),
+        ("gasoracle.border", Style::new().magenta()),
+        ("gasoracle.header", header),
+        ("acclist.border", Style::new().green()),
+        ("acclist.header", header),
+        ("acclist.selected_row", selected),
+        ("acclist.stuck_row", Style::new().red()),
+        ("acclist.withdrawn", Style::new().green()),
+        ("acclist.delta_up", Style::new().green()),
+        ("acclist.delta_down", Style::new().red()),
+        ("txlist.border", Style::new().blue()),
+        ("txlist.header", header),
+        ("txlist.selected_row", selected),
+        ("txlist.match_row", Style::new().cyan().bold().underlined()),
+        ("txlist.watched_row", Style::new().yellow().bold()),
+        ("txlist.failed_row", Style::new().red()),
+        ("txlist.search_error", Style::new().red()),
+        ("activity.border", Style::new().yellow()),
+        ("activity.header", header),
+        ("charts.border", Style::new().cyan()),
+        ("charts.line", Style::new().cyan()),
+        ("charts.cursor", Style::new().yellow()),
+        ("mempool.border", Style::new().blue()),
+        ("mempool.header", header),
+        ("mempool.selected_row", selected),
+        ("mempool.watched_row", Style::new().yellow().bold()),
+        ("mempool.included", Style::new().green()),
+        ("withdrawals.border", Style::new().green()),
+        ("withdrawals.header", header),
+        ("withdrawals.watched_row", Style::new().yellow().bold()),
+        ("stats.border", Style::new().cyan()),
+        ("details.border", Style::new().cyan()),
+        ("alerts.border", Style::new().yellow()),
+        ("alerts.selected_row", selected),
+        ("alerts.banner", Style::new().black().on_yellow()),
+        ("help.border", Style::new().cyan()),
+        ("help.hint", Style::new().black().on_cyan()),
+        ("command.line", Style::new().cyan()),
+        ("command.usage", Style::new().dark_gray()),
+        ("error.border", Style::new().red()),
+    ])
+}
+
+/// Styles of the high contrast theme which differ from the default.
+fn high_contrast_styles() -> HashMap<&'static str, Style> {
+    let border = Style::new().white().bold();
+    let header = Style::new().light_yellow().bold().underlined();
+    let selected = Style::new().black().on_light_yellow().bold();
+    HashMap::from([
+        ("focused_border", Style::new().light_yellow().bold()),
+        ("head.border", border),
+        ("head.selected_row", selected),
+        ("head.gauge_filled", Style::new().light_cyan()),
+        ("head.gauge_unfilled", Style::new().gray()),
+        ("gasoracle.border", border),
+        ("gasoracle.header", header),
+        ("acclist.border", border),
+        ("acclist.header", header),
+        ("acclist.selected_row", selected),
+        ("acclist.stuck_row", Style::new().light_red().bold()),
+        ("acclist.withdrawn", Style::new().light_green().bold()),
+        ("acclist.delta_up", Style::new().light_green().bold()),
+        ("acclist.delta_down", Style::new().light_red().bold()),
+        ("txlist.border", border),
+        ("txlist.header", header),
+        ("txlist.selected_row", selected),
+        (
+            "txlist.match_row",
+            Style::new().black().on_light_cyan().bold(),
+        ),
+        ("txlist.watched_row", Style::new().light_yellow().bold()),
+        ("txlist.failed_row", Style::new().light_red().bold()),
+        ("txlist.search_error", Style::new().light_red().bold()),
+        ("activity.border", border),
+        ("activity.header", header),
+        ("charts.border", border),
+        ("charts.line", Style::new().light_cyan()),
+        ("charts.cursor", Style::new().light_yellow()),
+        ("mempool.border", border),
+        ("mempool.header", header),
+        ("mempool.selected_row", selected),
+        ("mempool.watched_row", Style::new().light_yellow().bold()),
+        ("mempool.included", Style::new().light_green().bold()),
+        ("withdrawals.border", border),
+        ("withdrawals.header", header),
+        (
+            "withdrawals.watched_row",
+            Style::new().light_yellow().bold(),
+        ),
+        ("stats.border", border),
+        ("details.border", border),
+        ("alerts.border", border),
+        ("alerts.selected_row", selected),
+        (
+            "alerts.banner",
+            Style::new().black().on_light_yellow().bold(),
+        ),
+        ("help.border", border),
+        ("help.hint", Style::new().black().on_white().bold()),
+        ("command.line", border),
+        ("command.usage", Style::new().gray()),
+        ("error.border", Style::new().light_red().bold()),
+    ])
+}
+
+/// Styles of the monochrome theme which tell apart what the default theme
+/// tells apart by color alone.
+fn monochrome_styles() -> HashMap<&'static str, Style> {
+    HashMap::from([
+        ("head.gauge_filled", Style::new().bold()),
+        ("head.gauge_unfilled", Style::new().dim()),
+        ("acclist.stuck_row", Style::new().bold()),
+        ("acclist.delta_down", Style::new().italic()),
+        ("txlist.failed_row", Style::new().crossed_out()),
+        ("txlist.search_error", Style::new().bold()),
+        ("charts.cursor", Style::new().bold()),
+        ("mempool.included", Style::new().bold()),
+        ("alerts.banner", Style::new().reversed()),
+        ("help.hint", Style::new().reversed()),
+        ("command.usage", Style::new().dim()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui::style::{Color, Style, Stylize};
+
+    use super::{default_styles, high_contrast_styles, monochrome_styles, Theme, ThemeName};
+    use crate::config::{parse_style, Styles};
+
+    #[test]
+    fn themes() {
+        // Built-in themes only style keys of the default theme.
+        let keys = default_styles();
+        for (key, _) in high_contrast_styles().iter().chain(&monochrome_styles()) {
+            assert!(keys.contains_key(key), "{key}");
+        }
+
+        let styles = Styles(
+            [
+                ("txlist.border".to_string(), parse_style("red")),
+                ("txlist.unknown".to_string(), parse_style("red")),
+            ]
+            .into(),
+        );
+        let theme = Theme::new(None, &styles, false);
+        assert_eq!(theme.get("txlist.border").fg, Some(Color::Indexed(1)));
+        assert_eq!(theme.get("acclist.delta_up"), Style::new().green());
+        assert_eq!(theme.get("txlist.unknown"), Style::default());
+        assert!(theme.colors);
+
+        // Without colors unless a theme is configured.
+        let theme = Theme::new(None, &styles, true);
+        assert!(!theme.colors);
+        assert_eq!(theme.get("acclist.delta_up"), Style::new().fg(Color::Reset));
+        assert_eq!(theme.get("txlist.failed_row"), Style::new().crossed_out());
+        let theme = Theme::new(Some(ThemeName::HighContrast), &styles, true);
+        assert!(theme.colors);
+        assert_eq!(
+            theme.get("txlist.selected_row"),
+            Style::new().black().on_light_yellow().bold()
+        );
+    }
+}
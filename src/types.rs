@@ -1,4 +1,9 @@
-use std::fmt::Debug;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Debug},
+};
+
+use alloy::primitives::{utils::format_units, U256};
 
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 
@@ -8,6 +13,12 @@ pub type AccountSender = UnboundedSender<Vec<Account>>;
 pub type BlockReceiver = UnboundedReceiver<Vec<Block>>;
 pub type TransactionReceiver = UnboundedReceiver<Vec<Transaction>>;
 pub type AccountReceiver = UnboundedReceiver<Vec<Account>>;
+pub type FeesSender = UnboundedSender<Fees>;
+pub type FeesReceiver = UnboundedReceiver<Fees>;
+pub type WithdrawalSender = UnboundedSender<Vec<Withdrawal>>;
+pub type WithdrawalReceiver = UnboundedReceiver<Vec<Withdrawal>>;
+pub type BlockStatsSender = UnboundedSender<Vec<BlockStats>>;
+pub type BlockStatsReceiver = UnboundedReceiver<Vec<BlockStats>>;
 
 /// Contains the chain-agnostic data required to represent a block in the UI.
 #[derive(Debug, Clone, Default)]
@@ -15,26 +26,256 @@ pub struct Block {
     pub number: u64,
     pub hash: String,
     pub timestamp: u64,
+    /// Base fee per gas in the smallest unit of the native currency, if the
+    /// chain has one.
+    pub base_fee: Option<u64>,
+    pub gas_used: u64,
+    pub gas_limit: u64,
+    pub blob_gas_used: Option<u64>,
+    pub excess_blob_gas: Option<u64>,
+    /// Number of transactions in the block.
+    pub transactions: usize,
+    /// Size of the block in bytes, if known.
+    pub size: Option<u64>,
+    /// Address of the account receiving the fees of the block.
+    pub fee_recipient: String,
+    /// Extra data of the block, as text if it is printable and hex otherwise.
+    pub extra_data: String,
+    /// Seconds since the parent block, if known.
+    pub block_time: Option<u64>,
+    /// Blob usage of the block, if the chain supports blobs.
+    pub blob_usage: Option<BlobUsage>,
+}
+
+/// Contains the number of blobs carried by a block relative to its limits.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlobUsage {
+    pub blobs: u64,
+    pub target: u64,
+    pub max: u64,
+    /// Base fee per blob gas, in the smallest unit of the native currency.
+    pub base_fee: u128,
+}
+
+/// An amount of a currency in its smallest unit, along with the number of
+/// decimals and the symbol of the currency.
+///
+/// Amounts are ordered by value, so only amounts of the same currency should be
+/// compared.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Amount {
+    pub value: U256,
+    pub decimals: u8,
+    pub symbol: String,
+}
+
+impl Amount {
+    pub fn new(value: U256, decimals: u8, symbol: &str) -> Self {
+        Self {
+            value,
+            decimals,
+            symbol: symbol.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match format_units(self.value, self.decimals) {
+            Ok(value) => write!(f, "{value} {}", self.symbol),
+            Err(_) => write!(f, "{} {}", self.value, self.symbol),
+        }
+    }
 }
 
 /// Contains the chain-agnostic data required to represent a transaction block in the UI.
 #[derive(Debug, Clone, Default)]
 pub struct Transaction {
+    pub block: u64,
     pub hash: String,
     pub from: String,
     pub to: String,
-    pub value: String,
-    pub units: String,
-    pub kind: String,
-    pub nonce: String,
+    pub value: Amount,
+    pub kind: TxKind,
+    pub nonce: u64,
+    /// Price paid per unit of gas, in the smallest unit of the native currency.
+    pub gas_price: u128,
+    /// Priority fee paid per unit of gas on top of the base fee, in the
+    /// smallest unit of the native currency, if known.
+    pub tip: Option<u64>,
+    /// Gas used by the transaction, if known.
+    pub gas_used: Option<u64>,
+    /// Whether the transaction reverted, as known from its receipt.
+    pub failed: bool,
+    /// Blobs carried by the transaction, if it is a blob transaction.
+    pub blobs: Option<Blobs>,
+}
+
+/// Contains the blobs carried by a blob (EIP-4844) transaction. Fees are in
+/// the smallest unit of the native currency.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Blobs {
+    pub versioned_hashes: Vec<String>,
+    pub max_fee_per_blob_gas: u128,
+    /// Blob gas used by the blobs.
+    pub blob_gas: u64,
+    /// Cost of the blob gas at the blob base fee of the including block, once
+    /// included.
+    pub cost: Option<u128>,
+}
+
+/// The kind of a transaction, named by the EIP which introduced it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TxKind {
+    #[default]
+    Legacy,
+    AccessList,
+    DynamicFee,
+    Blob,
+    SetCode,
+    Other(u8),
+}
+
+impl From<u8> for TxKind {
+    fn from(ty: u8) -> Self {
+        match ty {
+            0 => Self::Legacy,
+            1 => Self::AccessList,
+            2 => Self::DynamicFee,
+            3 => Self::Blob,
+            4 => Self::SetCode,
+            ty => Self::Other(ty),
+        }
+    }
+}
+
+impl fmt::Display for TxKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Legacy => write!(f, "Legacy"),
+            Self::AccessList => write!(f, "EIP-2930"),
+            Self::DynamicFee => write!(f, "EIP-1559"),
+            Self::Blob => write!(f, "EIP-4844"),
+            Self::SetCode => write!(f, "EIP-7702"),
+            Self::Other(ty) => write!(f, "Type {ty}"),
+        }
+    }
 }
 
 /// Contains the chain-agnostic data required to represent an account block in the UI.
 #[derive(Debug, Clone, Default)]
 pub struct Account {
     pub address: String,
-    pub balance: String,
-    pub units: String,
+    pub balance: Amount,
+    /// Number of transactions sent from the account which are included in blocks.
+    pub nonce: u64,
+    /// Number of transactions sent from the account, including pending ones.
+    pub pending_nonce: u64,
+    /// Set when the pending transactions of the account have not been
+    /// included for too many blocks.
+    pub stuck: Option<Stuck>,
+    /// Total amount withdrawn to the account from validators in the block, if
+    /// any.
+    pub withdrawn: Option<Amount>,
+}
+
+/// Contains the chain-agnostic data required to represent a withdrawal from a
+/// validator in the UI.
+#[derive(Debug, Clone, Default)]
+pub struct Withdrawal {
+    pub index: u64,
+    pub validator: u64,
+    pub address: String,
+    pub amount: Amount,
+}
+
+/// A nonce gap which has lasted long enough for the pending transactions of
+/// an account to be considered stuck.
+#[derive(Debug, Clone, Default)]
+pub struct Stuck {
+    /// Number of blocks the gap has lasted for.
+    pub blocks: u64,
+    /// The pending transaction holding up the others, if it could be found.
+    pub transaction: Option<Transaction>,
+    /// Base fee per gas of the latest block, in the smallest unit of the
+    /// native currency.
+    pub base_fee: Option<u64>,
+}
+
+/// Contains fee suggestions for transactions to be included in upcoming
+/// blocks. Fees are per gas, in the smallest unit of the native currency.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fees {
+    /// The block the fees were estimated at.
+    pub block: u64,
+    /// Projected base fee of the next block.
+    pub next_base_fee: u64,
+    /// Priority fees for slow, standard and fast inclusion, derived from the
+    /// tips paid in recent blocks.
+    pub slow: u64,
+    pub standard: u64,
+    pub fast: u64,
+    /// Priority fee suggested by the node.
+    pub suggested: u64,
+}
+
+/// Contains statistics computed over the transactions of a block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockStats {
+    pub block: u64,
+    /// Minimum, median and maximum priority fee per gas paid in the block, in
+    /// the smallest unit of the native currency.
+    pub tips: Option<(u64, u64, u64)>,
+    /// Number of transactions of each kind present in the block.
+    pub kinds: Vec<(TxKind, usize)>,
+    /// Recipients of the transactions using the most gas, and the gas used.
+    pub top_consumers: Vec<(String, u64)>,
+    /// Number of transactions in the block.
+    pub transactions: usize,
+}
+
+impl BlockStats {
+    /// Number of recipients listed as top gas consumers.
+    const TOP_CONSUMERS: usize = 5;
+
+    /// Computes the statistics of a block from its transactions.
+    pub fn new(block: u64, transactions: &[Transaction]) -> Self {
+        // Tips.
+        let mut tips: Vec<_> = transactions.iter().filter_map(|tx| tx.tip).collect();
+        tips.sort_unstable();
+        let tips = match (tips.first(), tips.last()) {
+            (Some(min), Some(max)) => Some((*min, tips[tips.len() / 2], *max)),
+            _ => None,
+        };
+
+        // Kinds.
+        let mut kinds = BTreeMap::new();
+        for tx in transactions {
+            *kinds.entry(tx.kind).or_default() += 1;
+        }
+
+        // Gas consumers. Contract creations are attributed to the empty address.
+        let mut consumers = HashMap::<&str, u64>::new();
+        for tx in transactions {
+            if let Some(gas_used) = tx.gas_used {
+                *consumers.entry(tx.to.as_str()).or_default() += gas_used;
+            }
+        }
+        let mut top_consumers: Vec<_> = consumers
+            .into_iter()
+            .map(|(to, gas_used)| (to.to_string(), gas_used))
+            .collect();
+        top_consumers.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_consumers.truncate(Self::TOP_CONSUMERS);
+
+        Self {
+            block,
+            tips,
+            kinds: kinds.into_iter().collect(),
+            top_consumers,
+            transactions: transactions.len(),
+        }
+    }
 }
 
 /// Produces a shortened `String` representation of some type.
@@ -62,7 +303,38 @@ impl<T: AsRef<str>> Abridged for T {
 
 #[cfg(test)]
 mod tests {
-    use super::Abridged;
+    use super::{Abridged, BlockStats, Transaction, TxKind};
+
+    #[test]
+    fn block_stats() {
+        let tx = |kind: u8, tip, to: &str, gas_used| Transaction {
+            kind: kind.into(),
+            tip: Some(tip),
+            to: to.to_string(),
+            gas_used: Some(gas_used),
+            ..Default::default()
+        };
+        let txs = [
+            tx(2, 30, "0xa", 100),
+            tx(0, 10, "0xb", 300),
+            tx(2, 20, "0xa", 250),
+            tx(3, 40, "", 10),
+        ];
+        let stats = BlockStats::new(1, &txs);
+        assert_eq!(stats.tips, Some((10, 30, 40)));
+        assert_eq!(
+            stats.kinds,
+            vec![
+                (TxKind::Legacy, 1),
+                (TxKind::DynamicFee, 2),
+                (TxKind::Blob, 1)
+            ]
+        );
+        assert_eq!(stats.top_consumers[0], ("0xa".to_string(), 350));
+        assert_eq!(stats.top_consumers[1], ("0xb".to_string(), 300));
+        assert_eq!(BlockStats::new(2, &[]).tips, None);
+    }
+
     #[test]
     fn abridged() {
         // &str
@@ -0,0 +1,167 @@
+use alloy::primitives::U256;
+use serde::{Deserialize, Serialize};
+use strum::{EnumString, VariantNames};
+
+use crate::types::Amount;
+
+/// Number of decimals of gwei relative to wei.
+const GWEI_DECIMALS: u8 = 9;
+
+/// The denomination in which a panel displays amounts.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, EnumString, VariantNames,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Units {
+    /// The smallest unit of the native currency.
+    Wei,
+    /// A billionth of the native currency.
+    Gwei,
+    /// The native currency, labelled as ether.
+    Ether,
+    /// The native currency, labelled with its own symbol.
+    #[default]
+    Native,
+    /// Whichever of the above reads best for each amount, shown with the
+    /// amount.
+    Auto,
+}
+
+impl Units {
+    /// Returns the denomination selected after this one when cycling.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Native => Self::Ether,
+            Self::Ether => Self::Gwei,
+            Self::Gwei => Self::Wei,
+            Self::Wei => Self::Auto,
+            Self::Auto => Self::Native,
+        }
+    }
+
+    /// Name of the denomination for column headers and titles.
+    pub fn label(self, symbol: &str) -> String {
+        match self {
+            Self::Wei => "WEI".to_string(),
+            Self::Gwei => "GWEI".to_string(),
+            Self::Ether => "ETHER".to_string(),
+            Self::Native => symbol.to_uppercase(),
+            Self::Auto => "AUTO".to_string(),
+        }
+    }
+
+    /// Formats an amount with thousands separators and the given number of
+    /// decimal places. Auto-scaled amounts are followed by their denomination.
+    pub fn format(self, amount: &Amount, decimals: usize) -> String {
+        match self {
+            Self::Auto => self.format_with_symbol(amount, decimals),
+            units => separated(amount.value, units.scale(amount.decimals), decimals),
+        }
+    }
+
+    /// Formats an amount like [`Units::format`], always followed by its
+    /// denomination.
+    pub fn format_with_symbol(self, amount: &Amount, decimals: usize) -> String {
+        let units = match self {
+            Self::Auto => Self::scaled(amount),
+            units => units,
+        };
+        let symbol = match units {
+            Self::Wei => "wei",
+            Self::Gwei => "gwei",
+            Self::Ether => "ether",
+            _ => &amount.symbol,
+        };
+        let value = separated(amount.value, units.scale(amount.decimals), decimals);
+        format!("{value} {symbol}")
+    }
+
+    /// Number of decimals of the denomination relative to the smallest unit of
+    /// a currency with the given number of decimals.
+    fn scale(self, decimals: u8) -> u8 {
+        match self {
+            Self::Wei => 0,
+            Self::Gwei => GWEI_DECIMALS.min(decimals),
+            _ => decimals,
+        }
+    }
+
+    /// Picks the largest denomination in which the amount is at least a
+    /// thousandth.
+    fn scaled(amount: &Amount) -> Self {
+        let at_least = |units: Self| {
+            let scale = units.scale(amount.decimals).saturating_sub(3);
+            amount.value >= U256::from(10).pow(U256::from(scale))
+        };
+        [Self::Native, Self::Gwei]
+            .into_iter()
+            .find(|units| at_least(*units))
+            .unwrap_or(Self::Wei)
+    }
+}
+
+/// Formats a value scaled down by the given number of decimals, with thousands
+/// separators and at most the given number of decimal places.
+fn separated(value: U256, scale: u8, decimals: usize) -> String {
+    let (int, frac) = value.div_rem(U256::from(10).pow(U256::from(scale)));
+    let digits = int.to_string();
+    let mut formatted = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+    let places = decimals.min(scale as usize);
+    if places > 0 {
+        let frac = format!("{:0>width$}", frac.to_string(), width = scale as usize);
+        formatted.push('.');
+        formatted.push_str(&frac[..places]);
+    }
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::U256;
+    use pretty_assertions::assert_eq;
+
+    use super::Units;
+    use crate::types::Amount;
+
+    fn wei(value: u128) -> Amount {
+        Amount::new(U256::from(value), 18, "ETH")
+    }
+
+    #[test]
+    fn format() {
+        let whale = wei(1_234_567_891_000_000_000_000);
+        assert_eq!(Units::Native.format(&whale, 4), "1,234.5678");
+        assert_eq!(Units::Ether.format(&whale, 0), "1,234");
+        assert_eq!(Units::Gwei.format(&whale, 2), "1,234,567,891,000.00");
+        assert_eq!(Units::Wei.format(&wei(1_000), 4), "1,000");
+        assert_eq!(Units::Native.format(&wei(5), 2), "0.00");
+        assert_eq!(Units::Native.label("eth"), "ETH");
+    }
+
+    #[test]
+    fn auto() {
+        assert_eq!(
+            Units::Auto.format(&wei(1_500_000_000_000_000_000), 2),
+            "1.50 ETH"
+        );
+        assert_eq!(Units::Auto.format(&wei(21_000_000_000), 1), "21.0 gwei");
+        assert_eq!(Units::Auto.format(&wei(999), 1), "999 wei");
+        assert_eq!(Units::Wei.format_with_symbol(&wei(7), 1), "7 wei");
+    }
+
+    #[test]
+    fn cycle() {
+        let mut units = Units::default();
+        for _ in 0..5 {
+            units = units.next();
+        }
+        assert_eq!(units, Units::default());
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+};
+
+use crate::{labels::AddressBook, types::Transaction};
+
+/// Addresses of the accounts being watched, shared between the chain monitor
+/// and the UI.
+///
+/// The chain monitor keeps the set up to date with the accounts it retrieves
+/// balances for, which includes accounts specified by name. Its version is
+/// bumped whenever the set changes.
+#[derive(Clone, Debug, Default)]
+pub struct Watchlist(Arc<RwLock<HashSet<String>>>, Arc<AtomicU64>);
+
+impl Watchlist {
+    /// Replaces the set of watched addresses.
+    pub fn replace(&self, addresses: impl IntoIterator<Item = String>) {
+        let addresses: HashSet<_> = addresses.into_iter().map(|a| a.to_lowercase()).collect();
+        if let Ok(mut watched) = self.0.write() {
+            if *watched != addresses {
+                *watched = addresses;
+                self.1.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Returns a number which changes whenever the set of watched addresses
+    /// changes.
+    pub fn version(&self) -> u64 {
+        self.1.load(Ordering::Relaxed)
+    }
+
+    /// Returns the watched addresses, sorted.
+    pub fn addresses(&self) -> Vec<String> {
+        let mut addresses: Vec<_> = self
+            .0
+            .read()
+            .map(|watched| watched.iter().cloned().collect())
+            .unwrap_or_default();
+        addresses.sort();
+        addresses
+    }
+
+    /// Returns true if the address is watched.
+    pub fn contains(&self, address: &str) -> bool {
+        self.0
+            .read()
+            .is_ok_and(|watched| watched.contains(&address.to_lowercase()))
+    }
+
+    /// Returns true if the address is watched or labelled.
+    pub fn is_relevant(&self, address: &str, labels: &AddressBook) -> bool {
+        !address.is_empty() && (self.contains(address) || labels.get(address).is_some())
+    }
+
+    /// Returns true if the transaction is from or to a watched or labelled address.
+    pub fn matches(&self, tx: &Transaction, labels: &AddressBook) -> bool {
+        self.is_relevant(&tx.from, labels) || self.is_relevant(&tx.to, labels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Watchlist;
+    use crate::{
+        labels::{AddressBook, Label},
+        types::Transaction,
+    };
+
+    #[test]
+    fn matches() {
+        let watchlist = Watchlist::default();
+        let labels = AddressBook::default();
+        watchlist.replace(["0xAAAA".to_string()]);
+        labels.set(
+            "0xbbbb",
+            Some(Label {
+                name: "b".to_string(),
+                color: None,
+            }),
+        );
+
+        let tx = |from: &str, to: &str| Transaction {
+            from: from.to_string(),
+            to: to.to_string(),
+            ..Default::default()
+        };
+        assert!(watchlist.matches(&tx("0xaaaa", "0xcccc"), &labels));
+        assert!(watchlist.matches(&tx("0xcccc", "0xBBBB"), &labels));
+        assert!(!watchlist.matches(&tx("0xcccc", ""), &labels));
+
+        watchlist.replace([]);
+        assert!(!watchlist.matches(&tx("0xaaaa", "0xcccc"), &labels));
+    }
+}